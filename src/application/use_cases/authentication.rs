@@ -2,79 +2,212 @@
 use std::{ sync::Arc};
 use anyhow::Result;
 use chrono::{Duration, Utc};
+use uuid::Uuid;
 
-use crate::{domain::repositories::brawlers::BrawlerRepository, infrastructure::{self, jwt::{authentication_model::LoginModel, jwt_model::{Claims, Passport}}}};
+use crate::{application::use_cases::two_factor::verify_second_factor, domain::{app_error::{AppError, AppResult}, entities::{brawlers::BrawlerEntity, sessions::NewSession}, repositories::{brawlers::BrawlerRepository, recovery_codes::RecoveryCodeRepository, sessions::SessionRepository}}, infrastructure::{self, jwt::{authentication_model::{LoginModel, MfaLoginModel}, keys::JwtKeys, jwt_model::{Claims, LoginOutcome, MfaChallenge, Passport, TokenType}}}};
 
-pub struct AuthenticationUseCase<T>
+pub const MFA_TOKEN_TTL_MINUTES: i64 = 5;
+
+pub struct AuthenticationUseCase<T1, T2, T3>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    session_repository: Arc<T2>,
+    recovery_code_repository: Arc<T3>,
 }
 
 
 
 
-impl <T> AuthenticationUseCase<T> 
+impl <T1, T2, T3> AuthenticationUseCase<T1, T2, T3>
     where
-        T: BrawlerRepository + Send + Sync,
+        T1: BrawlerRepository + Send + Sync,
+        T2: SessionRepository + Send + Sync,
+        T3: RecoveryCodeRepository + Send + Sync,
     {
-        pub fn new(brawler_repository: Arc<T>) -> Self {
-            Self { brawler_repository }
+        pub fn new(brawler_repository: Arc<T1>, session_repository: Arc<T2>, recovery_code_repository: Arc<T3>) -> Self {
+            Self { brawler_repository, session_repository, recovery_code_repository }
 
         }
 
-        pub async fn login(&self, login_model: LoginModel) -> Result<Passport>{
+        pub async fn login(&self, login_model: LoginModel) -> AppResult<LoginOutcome>{
 
-            let secret_env = get_user_secret_env()?;
             let username = login_model.username.clone();
 
-            let brawler = self.brawler_repository.find_by_username(username).await?;
+            // An unknown username gets the same answer as a wrong password.
+            let brawler = match self.brawler_repository.find_by_username(username).await.map_err(AppError::from) {
+                Ok(brawler) => brawler,
+                Err(AppError::NotFound(_)) => return Err(invalid_credentials()),
+                Err(e) => return Err(e),
+            };
 
-            let hash_password = brawler.password;
+            let hash_password = brawler.password.clone();
             let login_password = login_model.password;
 
             if !infrastructure::argon2::verify(login_password, hash_password)? {
-                return Err(anyhow::anyhow!("Invalid username or password"));
+                return Err(invalid_credentials());
+            }
+
+            if brawler.totp_enabled {
+                return Ok(LoginOutcome::MfaPending(self.issue_mfa_challenge(brawler.id)?));
+            }
+
+            Ok(LoginOutcome::Passport(self.issue_passport(brawler).await?))
+        }
+
+        pub async fn login_mfa(&self, mfa_login_model: MfaLoginModel) -> AppResult<Passport> {
+            let mfa_keys = get_mfa_keys()?;
+
+            let claims = infrastructure::jwt::verify_token(&mfa_keys, mfa_login_model.mfa_token)
+                .map_err(|_| invalid_token("Invalid MFA token"))?;
+            if claims.typ != TokenType::MfaPending {
+                return Err(invalid_token("Invalid MFA token"));
             }
 
+            let brawler_id = claims.sub.parse::<i32>().map_err(|_| invalid_token("Invalid MFA token"))?;
+            let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+
+            if !verify_second_factor(self.brawler_repository.as_ref(), self.recovery_code_repository.as_ref(), &brawler, &mfa_login_model.code).await? {
+                return Err(AppError::Unauthorized("Invalid two-factor code".to_string()));
+            }
+
+            Ok(self.issue_passport(brawler).await?)
+        }
+
+        pub async fn refresh_token(&self, refresh_token: String) -> AppResult<Passport> {
+            let refresh_keys = get_refresh_keys()?;
+
+            let claims = infrastructure::jwt::verify_token(&refresh_keys, refresh_token)
+                .map_err(|_| invalid_token("Invalid refresh token"))?;
+            if claims.typ != TokenType::Refresh {
+                return Err(invalid_token("Invalid refresh token"));
+            }
+
+            let brawler_id = claims.sub.parse::<i32>().map_err(|_| invalid_token("Invalid refresh token"))?;
+
+            // Rotate: the old session is revoked so its refresh token can only be used once.
+            // Checking and revoking in one statement means only one of two concurrent
+            // refreshes with the same token can win.
+            if self.session_repository.revoke(claims.jti).await? == 0 {
+                return Err(invalid_token("Session has been revoked"));
+            }
+
+            let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+
+            Ok(self.issue_passport(brawler).await?)
+        }
+
+        pub async fn logout(&self, jti: String) -> AppResult<()> {
+            // Logging out of an already revoked session is not an error.
+            self.session_repository.revoke(jti).await?;
+            Ok(())
+        }
+
+        pub async fn logout_all(&self, brawler_id: i32) -> AppResult<()> {
+            Ok(self.session_repository.revoke_all(brawler_id).await?)
+        }
+
+        // The MFA token only proves the password step. It is signed with its own secret,
+        // so neither the auth middleware nor anything trusting the published JWKS will
+        // take it for an access token.
+        fn issue_mfa_challenge(&self, brawler_id: i32) -> Result<MfaChallenge> {
+            let mfa_keys = get_mfa_keys()?;
+
+            let claims = Claims {
+                sub: brawler_id.to_string(),
+                jti: Uuid::new_v4().to_string(),
+                typ: TokenType::MfaPending,
+                exp: (Utc::now() + Duration::minutes(MFA_TOKEN_TTL_MINUTES)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            };
+
+            let mfa_token = infrastructure::jwt::generate_token(&mfa_keys, &claims)?;
+
+            Ok(MfaChallenge {
+                mfa_token,
+                expires_in: (MFA_TOKEN_TTL_MINUTES * 60) as usize,
+            })
+        }
+
+        async fn issue_passport(&self, brawler: BrawlerEntity) -> Result<Passport> {
+            let access_keys = infrastructure::jwt::access_keys()?;
+            let refresh_keys = get_refresh_keys()?;
+
+            let jti = Uuid::new_v4().to_string();
+            let session_expires_at = Utc::now() + Duration::days(3);
+
             let access_token_claims = Claims {
                 sub: brawler.id.to_string(),
+                jti: jti.clone(),
+                typ: TokenType::Access,
                 exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
                 iat: Utc::now().timestamp() as usize,
             };
 
-            let access_token = 
-            infrastructure::jwt::generate_token(secret_env.clone(), &access_token_claims)?;
+            let access_token =
+            infrastructure::jwt::generate_token(access_keys, &access_token_claims)?;
 
             let refresh_token_claims = Claims {
                 sub: brawler.id.to_string(),
-                exp: (Utc::now() + Duration::days(3)).timestamp() as usize,
+                jti: jti.clone(),
+                typ: TokenType::Refresh,
+                exp: session_expires_at.timestamp() as usize,
                 iat: Utc::now().timestamp() as usize,
             };
 
-            let refresh_token = 
-            infrastructure::jwt::generate_token(secret_env, &refresh_token_claims)?;
-            
+            let refresh_token =
+            infrastructure::jwt::generate_token(&refresh_keys, &refresh_token_claims)?;
+
+            self.session_repository
+                .create(NewSession {
+                    jti,
+                    brawler_id: brawler.id,
+                    expires_at: session_expires_at.naive_utc(),
+                })
+                .await?;
+
             Ok(Passport{
                 refresh_token,
                 access_token,
                 token_type: "Bearer".to_string(),
                 expires_in: access_token_claims.exp,
-                display_name: brawler.username, // Using username as display_name for now
-                avatar_url: None, // Avatar not yet supported in DB
+                display_name: brawler.display_name,
+                avatar_url: brawler.avatar_url,
             })
+        }
+    }
 
+fn invalid_credentials() -> AppError {
+    AppError::Unauthorized("Invalid username or password".to_string())
+}
 
-        }
+fn invalid_token(message: &str) -> AppError {
+    AppError::Unauthorized(message.to_string())
+}
 
-        pub async fn refresh_token(){
-            
-        }
-    }
-    
-pub fn get_user_secret_env() -> Result<String>{
-    let secret_env = std::env::var("JWT_USER_SECRET")
-    .map_err(|_| anyhow::anyhow!("JWT_USER_SECRET environment variable not set"))?;
+pub fn get_user_refresh_secret_env() -> Result<String>{
+    let secret_env = std::env::var("JWT_USER_REFRESH_SECRET")
+    .map_err(|_| anyhow::anyhow!("JWT_USER_REFRESH_SECRET environment variable not set"))?;
     Ok(secret_env)
-}
\ No newline at end of file
+}
+
+// Refresh tokens are only ever read back by this service, so they keep a shared HS256
+// secret even when access tokens are signed with an asymmetric key.
+pub fn get_refresh_keys() -> Result<JwtKeys> {
+    Ok(JwtKeys::from_secret(&get_user_refresh_secret_env()?))
+}
+
+pub fn get_mfa_secret_env() -> Result<String> {
+    let secret_env = std::env::var("JWT_MFA_SECRET")
+        .map_err(|_| anyhow::anyhow!("JWT_MFA_SECRET environment variable not set"))?;
+    Ok(secret_env)
+}
+
+// Like refresh tokens, MFA tokens never leave this service, and a secret that is never
+// published keeps them from verifying against the access token JWKS.
+pub fn get_mfa_keys() -> Result<JwtKeys> {
+    Ok(JwtKeys::from_secret(&get_mfa_secret_env()?))
+}
@@ -0,0 +1,407 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        application::use_cases::authentication::{
+            AuthenticationUseCase, MFA_TOKEN_TTL_MINUTES, get_mfa_keys, get_refresh_keys,
+        },
+        domain::{
+            app_error::AppError,
+            entities::brawlers::BrawlerEntity,
+            repositories::{
+                brawlers::MockBrawlerRepository, recovery_codes::MockRecoveryCodeRepository,
+                sessions::MockSessionRepository,
+            },
+        },
+        infrastructure::{
+            argon2::hash,
+            jwt::{
+                authentication_model::{LoginModel, MfaLoginModel},
+                access_keys, generate_token,
+                jwt_model::{Claims, LoginOutcome, TokenType},
+                verify_token,
+            },
+            totp,
+        },
+    };
+
+    fn brawler(password: &str) -> BrawlerEntity {
+        let now = Utc::now().naive_utc();
+        BrawlerEntity {
+            id: 1,
+            username: "menta".to_string(),
+            password: hash(password.to_string()).unwrap(),
+            created_at: now,
+            updated_at: now,
+            email: None,
+            totp_secret: None,
+            totp_enabled: false,
+            display_name: "Menta".to_string(),
+            avatar_url: None,
+            avatar_public_id: None,
+            totp_failed_attempts: 0,
+            totp_locked_until: None,
+            totp_last_step: None,
+        }
+    }
+
+    fn two_factor_brawler(secret: String) -> BrawlerEntity {
+        BrawlerEntity {
+            totp_secret: Some(secret),
+            totp_enabled: true,
+            ..brawler("P@ssw0rd")
+        }
+    }
+
+    fn mfa_token() -> String {
+        generate_token(
+            &get_mfa_keys().unwrap(),
+            &Claims {
+                sub: "1".to_string(),
+                jti: "mfa-1".to_string(),
+                typ: TokenType::MfaPending,
+                exp: (Utc::now() + Duration::minutes(5)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_login_issues_refresh_token() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_session_repository = MockSessionRepository::new();
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Ok(brawler("P@ssw0rd")) }));
+        mock_session_repository
+            .expect_create()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let LoginOutcome::Passport(passport) = use_case
+            .login(LoginModel {
+                username: "menta".to_string(),
+                password: "P@ssw0rd".to_string(),
+            })
+            .await
+            .unwrap()
+        else {
+            panic!("expected a passport");
+        };
+
+        let claims =
+            verify_token(&get_refresh_keys().unwrap(), passport.refresh_token).unwrap();
+        assert_eq!(claims.typ, TokenType::Refresh);
+        assert_eq!(claims.sub, "1");
+        assert_eq!(passport.display_name, "Menta");
+    }
+
+    #[tokio::test]
+    async fn test_login_wrong_password() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Ok(brawler("P@ssw0rd")) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let result = use_case
+            .login(LoginModel {
+                username: "menta".to_string(),
+                password: "wrong".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    #[tokio::test]
+    async fn test_login_with_two_factor_returns_mfa_challenge() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+
+        mock_brawler_repository.expect_find_by_username().returning(|_| {
+            Box::pin(async { Ok(two_factor_brawler(totp::generate_secret())) })
+        });
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let LoginOutcome::MfaPending(challenge) = use_case
+            .login(LoginModel {
+                username: "menta".to_string(),
+                password: "P@ssw0rd".to_string(),
+            })
+            .await
+            .unwrap()
+        else {
+            panic!("expected an MFA challenge");
+        };
+
+        let claims = verify_token(&get_mfa_keys().unwrap(), challenge.mfa_token.clone()).unwrap();
+        assert_eq!(claims.typ, TokenType::MfaPending);
+        // Not signed with the access key, so the published JWKS cannot vouch for it.
+        assert!(verify_token(access_keys().unwrap(), challenge.mfa_token).is_err());
+        assert_eq!(challenge.expires_in, (MFA_TOKEN_TTL_MINUTES * 60) as usize);
+    }
+
+    #[tokio::test]
+    async fn test_login_mfa_with_totp_code() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_session_repository = MockSessionRepository::new();
+
+        let secret = totp::generate_secret();
+        let code = totp::generate_current(&secret).unwrap();
+
+        mock_brawler_repository.expect_find_by_id().returning(move |_| {
+            let secret = secret.clone();
+            Box::pin(async move { Ok(two_factor_brawler(secret)) })
+        });
+        mock_brawler_repository
+            .expect_record_totp_step()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+        mock_brawler_repository
+            .expect_reset_second_factor_failures()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_session_repository
+            .expect_create()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let result = use_case
+            .login_mfa(MfaLoginModel {
+                mfa_token: mfa_token(),
+                code,
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_login_mfa_rejects_access_token() {
+        dotenvy::dotenv().ok();
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(MockBrawlerRepository::new()),
+            Arc::new(MockSessionRepository::new()),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let access_token = generate_token(
+            access_keys().unwrap(),
+            &Claims {
+                sub: "1".to_string(),
+                jti: "session-1".to_string(),
+                typ: TokenType::Access,
+                exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            },
+        )
+        .unwrap();
+
+        let result = use_case
+            .login_mfa(MfaLoginModel {
+                mfa_token: access_token,
+                code: "000000".to_string(),
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Invalid MFA token");
+    }
+
+    #[tokio::test]
+    async fn test_login_mfa_wrong_code() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        mock_brawler_repository.expect_find_by_id().returning(|_| {
+            Box::pin(async { Ok(two_factor_brawler(totp::generate_secret())) })
+        });
+        mock_recovery_code_repository
+            .expect_find_unused()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_brawler_repository
+            .expect_record_second_factor_failure()
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(MockSessionRepository::new()),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let result = use_case
+            .login_mfa(MfaLoginModel {
+                mfa_token: mfa_token(),
+                code: "not-a-code".to_string(),
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Invalid two-factor code");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_session_repository = MockSessionRepository::new();
+
+        mock_brawler_repository
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(brawler("P@ssw0rd")) }));
+        mock_session_repository
+            .expect_revoke()
+            .withf(|jti| jti == "session-1")
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_session_repository
+            .expect_create()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let refresh_token = generate_token(
+            &get_refresh_keys().unwrap(),
+            &Claims {
+                sub: "1".to_string(),
+                jti: "session-1".to_string(),
+                typ: TokenType::Refresh,
+                exp: (Utc::now() + Duration::days(3)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            },
+        )
+        .unwrap();
+
+        let passport = use_case.refresh_token(refresh_token).await.unwrap();
+
+        let claims = verify_token(access_keys().unwrap(), passport.access_token).unwrap();
+        assert_eq!(claims.typ, TokenType::Access);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_rejects_access_token() {
+        dotenvy::dotenv().ok();
+        let mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let access_token = generate_token(
+            &get_refresh_keys().unwrap(),
+            &Claims {
+                sub: "1".to_string(),
+                jti: "session-1".to_string(),
+                typ: TokenType::Access,
+                exp: (Utc::now() + Duration::days(1)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            },
+        )
+        .unwrap();
+
+        let result = use_case.refresh_token(access_token).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_rejects_revoked_session() {
+        dotenvy::dotenv().ok();
+        let mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_session_repository = MockSessionRepository::new();
+
+        // Already revoked, e.g. by a concurrent refresh with the same token.
+        mock_session_repository
+            .expect_revoke()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let refresh_token = generate_token(
+            &get_refresh_keys().unwrap(),
+            &Claims {
+                sub: "1".to_string(),
+                jti: "session-1".to_string(),
+                typ: TokenType::Refresh,
+                exp: (Utc::now() + Duration::days(3)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            },
+        )
+        .unwrap();
+
+        let result = use_case.refresh_token(refresh_token).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Session has been revoked");
+    }
+
+    #[tokio::test]
+    async fn test_logout_all() {
+        let mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_session_repository = MockSessionRepository::new();
+
+        mock_session_repository
+            .expect_revoke_all()
+            .withf(|brawler_id| *brawler_id == 1)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+
+        let result = use_case.logout_all(1).await;
+
+        assert!(result.is_ok());
+    }
+}
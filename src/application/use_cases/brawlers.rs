@@ -1,30 +1,49 @@
 use std::sync::Arc;
-use crate::domain::value_objects::{brawler_model::RegisterBrawlerModel, mission_model::MissionModel};
+use lettre::message::Mailbox;
+use tracing::warn;
+use crate::domain::app_error::{AppError, AppResult};
+use crate::domain::value_objects::{base64_image::Base64Image, brawler_model::{RegisterBrawlerModel, UpdateEmailModel}, mission_model::MissionModel, tag_model::group_mission_tags};
 use crate::infrastructure::argon2::hash;
-use crate::domain::repositories::brawlers::BrawlerRepository;
+use crate::domain::repositories::{brawlers::BrawlerRepository, image_storage::ImageStorage};
 
-pub struct BrawlersUseCase<T>
+pub const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+pub const AVATAR_FOLDER: &str = "avatars";
+
+pub struct BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: ImageStorage + Send + Sync + ?Sized,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    image_storage: Arc<T2>,
 }
 
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2> BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: ImageStorage + Send + Sync + ?Sized,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(brawler_repository: Arc<T1>, image_storage: Arc<T2>) -> Self {
+        Self { brawler_repository, image_storage }
     }
 
-    pub async fn register(&self, mut register_brawler_model: RegisterBrawlerModel) -> Result<i32, anyhow::Error> {
+    pub async fn register(&self, mut register_brawler_model: RegisterBrawlerModel) -> AppResult<i32> {
         // Check if username already exists
-        if let Ok(_) = self.brawler_repository.find_by_username(register_brawler_model.username.clone()).await {
-            return Err(anyhow::anyhow!("Username already exists"));
+        if self.brawler_repository.find_by_username(register_brawler_model.username.clone()).await.is_ok() {
+            return Err(AppError::Conflict("Username already exists".to_string()));
+        }
+
+        let display_name_length = register_brawler_model.display_name.trim().chars().count();
+        if display_name_length == 0 || display_name_length > MAX_DISPLAY_NAME_LENGTH {
+            return Err(AppError::Validation(format!(
+                "Display name must be between 1 and {} characters",
+                MAX_DISPLAY_NAME_LENGTH
+            )));
         }
 
+        register_brawler_model.email = normalize_email(register_brawler_model.email.take())?;
+
         let hashed_password = hash(register_brawler_model.password.clone())?;
 
         register_brawler_model.password = hashed_password;
@@ -36,21 +55,63 @@ where
         Ok(id)
     }
 
-    pub async fn upload_avatar(&self, base64_string: String, id: i32) -> Result<String, anyhow::Error> {
-        // For now, just pass the base64 string or a dummy URL
-        // In a real app, you would upload to S3 or save to disk and return the URL
-        let avatar_url = format!("data:image/png;base64,{}", base64_string); 
-        self.brawler_repository.update_avatar(id, avatar_url.clone()).await?;
-        Ok(avatar_url)
+    pub async fn update_email(&self, id: i32, update_email_model: UpdateEmailModel) -> AppResult<()> {
+        let email = normalize_email(update_email_model.email)?;
+
+        self.brawler_repository.update_email(id, email).await?;
+
+        Ok(())
     }
 
-    pub async fn get_my_missions(&self, brawler_id: i32) -> Result<Vec<MissionModel>, anyhow::Error> {
-        let missions = self.brawler_repository.get_missions(brawler_id).await?;
-        let mut mission_models = Vec::new();
-        for mission in missions {
-            let count = self.brawler_repository.crew_counting(mission.id).await?;
-            mission_models.push(mission.to_model(count as i64));
+    pub async fn upload_avatar(&self, base64_string: String, id: i32) -> AppResult<String> {
+        let base64_image = Base64Image::new(&base64_string)
+            .map_err(|e| AppError::Validation(e.to_string()))?;
+
+        let brawler = self.brawler_repository.find_by_id(id).await?;
+
+        let uploaded_image = self.image_storage.upload(base64_image, AVATAR_FOLDER).await?;
+        self.brawler_repository
+            .update_avatar(id, uploaded_image.url.clone(), Some(uploaded_image.public_id))
+            .await?;
+
+        // The new avatar is already saved, so a leftover old image is not worth failing for.
+        if let Some(previous_public_id) = brawler.avatar_public_id
+            && let Err(e) = self.image_storage.delete(&previous_public_id).await
+        {
+            warn!("Failed to delete previous avatar {}: {}", previous_public_id, e);
         }
+
+        Ok(uploaded_image.url)
+    }
+
+    pub async fn get_my_missions(&self, brawler_id: i32) -> AppResult<Vec<MissionModel>> {
+        let missions = self.brawler_repository.get_missions(brawler_id).await?;
+
+        let mission_ids: Vec<i32> = missions.iter().map(|(mission, ..)| mission.id).collect();
+        let mut tags = group_mission_tags(self.brawler_repository.mission_tags(&mission_ids).await?);
+
+        let mission_models = missions
+            .iter()
+            .map(|(mission, chief, crew_count)| {
+                let mut model = mission.to_model(chief, *crew_count);
+                model.tags = tags.remove(&mission.id).unwrap_or_default();
+                model
+            })
+            .collect();
         Ok(mission_models)
     }
-}
\ No newline at end of file
+}
+
+// Blank means no address. Anything else has to be one the mailer can send to, so reset
+// mails do not fail later; only the bare address is kept.
+fn normalize_email(email: Option<String>) -> AppResult<Option<String>> {
+    let Some(email) = email.as_deref().map(str::trim).filter(|email| !email.is_empty()) else {
+        return Ok(None);
+    };
+
+    let mailbox = email
+        .parse::<Mailbox>()
+        .map_err(|_| AppError::Validation("Email is not a valid address".to_string()))?;
+
+    Ok(Some(mailbox.email.to_string()))
+}
@@ -1,34 +1,72 @@
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
+    use chrono::Utc;
     use diesel;
 
     use crate::{
-        application::use_cases::brawlers::BrawlersUseCase,
+        application::use_cases::brawlers::{AVATAR_FOLDER, BrawlersUseCase},
         domain::{
-            repositories::brawlers::MockBrawlerRepository,
-            value_objects::brawler_model::RegisterBrawlerModel,
+            app_error::AppError,
+            entities::brawlers::BrawlerEntity,
+            repositories::{brawlers::MockBrawlerRepository, image_storage::ImageStorage},
+            value_objects::{
+                base64_image::Base64Image,
+                brawler_model::{RegisterBrawlerModel, UpdateEmailModel},
+            },
         },
+        infrastructure::image_storage::memory::InMemoryImageStorage,
     };
 
+    // A 1x1 transparent PNG.
+    const PNG_BASE64: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR4nGNgAAACAAFUok9dAAAAAElFTkSuQmCC";
+    // Just the JPEG and GIF headers, enough for the type to be detected.
+    const JPEG_BASE64: &str = "/9j/4AAQSkZJRgABAQ==";
+    const GIF_BASE64: &str = "R0lGODlhAQABAAAAACw=";
+
+    fn brawler(avatar_public_id: Option<String>) -> BrawlerEntity {
+        let now = Utc::now().naive_utc();
+        BrawlerEntity {
+            id: 1,
+            username: "menta".to_string(),
+            password: "hashed".to_string(),
+            created_at: now,
+            updated_at: now,
+            email: None,
+            totp_secret: None,
+            totp_enabled: false,
+            display_name: "Menta".to_string(),
+            avatar_url: None,
+            avatar_public_id,
+            totp_failed_attempts: 0,
+            totp_locked_until: None,
+            totp_last_step: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_brawler_register() {
         let mut mock_brawler_repository = MockBrawlerRepository::new();
 
         mock_brawler_repository
             .expect_register()
+            .withf(|entity| entity.display_name == "menta")
             .returning(|_| Box::pin(async { Ok(1) }));
         
         mock_brawler_repository
             .expect_find_by_username()
             .returning(|_| Box::pin(async { Err(anyhow::Error::from(diesel::result::Error::NotFound)) }));
 
-        let brawlers_use_case = BrawlersUseCase::new(Arc::new(mock_brawler_repository));
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(InMemoryImageStorage::new()),
+        );
 
         let register_brawler_model = RegisterBrawlerModel {
             username: "menta".to_string(),
             password: "P@ssw0rd".to_string(),
-            display_name: "menta".to_string()
+            display_name: "menta".to_string(),
+            email: None,
         };
 
         let result = brawlers_use_case
@@ -38,4 +76,184 @@ mod tests {
 
         assert_eq!(result, 1);
     }
+
+    #[tokio::test]
+    async fn test_brawler_register_blank_display_name() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Err(anyhow::Error::from(diesel::result::Error::NotFound)) }));
+
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(InMemoryImageStorage::new()),
+        );
+
+        let register_brawler_model = RegisterBrawlerModel {
+            username: "menta".to_string(),
+            password: "P@ssw0rd".to_string(),
+            display_name: "   ".to_string(),
+            email: None,
+        };
+
+        let result = brawlers_use_case.register(register_brawler_model).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_brawler_register_blank_email_is_no_email() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+
+        mock_brawler_repository
+            .expect_register()
+            .withf(|entity| entity.email.is_none())
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Err(anyhow::Error::from(diesel::result::Error::NotFound)) }));
+
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(InMemoryImageStorage::new()),
+        );
+
+        let register_brawler_model = RegisterBrawlerModel {
+            username: "menta".to_string(),
+            password: "P@ssw0rd".to_string(),
+            display_name: "menta".to_string(),
+            email: Some("  ".to_string()),
+        };
+
+        let result = brawlers_use_case.register(register_brawler_model).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_brawler_register_malformed_email() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+
+        mock_brawler_repository.expect_register().never();
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Err(anyhow::Error::from(diesel::result::Error::NotFound)) }));
+
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(InMemoryImageStorage::new()),
+        );
+
+        let register_brawler_model = RegisterBrawlerModel {
+            username: "menta".to_string(),
+            password: "P@ssw0rd".to_string(),
+            display_name: "menta".to_string(),
+            email: Some("menta.example.com".to_string()),
+        };
+
+        let result = brawlers_use_case.register(register_brawler_model).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_email_keeps_trimmed_address() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+
+        mock_brawler_repository
+            .expect_update_email()
+            .withf(|id, email| *id == 1 && email.as_deref() == Some("menta@example.com"))
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(InMemoryImageStorage::new()),
+        );
+
+        let result = brawlers_use_case
+            .update_email(
+                1,
+                UpdateEmailModel {
+                    email: Some(" menta@example.com ".to_string()),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_upload_avatar_replaces_previous_avatar() {
+        let image_storage = Arc::new(InMemoryImageStorage::new());
+        let previous = image_storage
+            .upload(Base64Image::new(PNG_BASE64).unwrap(), AVATAR_FOLDER)
+            .await
+            .unwrap();
+
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let previous_public_id = previous.public_id.clone();
+        mock_brawler_repository.expect_find_by_id().returning(move |_| {
+            let previous_public_id = previous_public_id.clone();
+            Box::pin(async move { Ok(brawler(Some(previous_public_id))) })
+        });
+        mock_brawler_repository
+            .expect_update_avatar()
+            .withf(|id, url, public_id| *id == 1 && !url.is_empty() && public_id.is_some())
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let brawlers_use_case =
+            BrawlersUseCase::new(Arc::new(mock_brawler_repository), Arc::clone(&image_storage));
+
+        let url = brawlers_use_case
+            .upload_avatar(PNG_BASE64.to_string(), 1)
+            .await
+            .unwrap();
+
+        assert!(url.starts_with("memory://avatars/"));
+        assert!(!image_storage.contains(&previous.public_id));
+        assert_eq!(image_storage.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_upload_avatar_rejects_non_image() {
+        let image_storage = Arc::new(InMemoryImageStorage::new());
+
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(MockBrawlerRepository::new()),
+            Arc::clone(&image_storage),
+        );
+
+        let result = brawlers_use_case
+            .upload_avatar("aGVsbG8=".to_string(), 1)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert!(image_storage.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upload_avatar_rejects_unsupported_image_type() {
+        let image_storage = Arc::new(InMemoryImageStorage::new());
+
+        let brawlers_use_case = BrawlersUseCase::new(
+            Arc::new(MockBrawlerRepository::new()),
+            Arc::clone(&image_storage),
+        );
+
+        let result = brawlers_use_case
+            .upload_avatar(GIF_BASE64.to_string(), 1)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert!(image_storage.is_empty());
+    }
+
+    #[test]
+    fn test_base64_image_extension_follows_detected_type() {
+        assert_eq!(Base64Image::new(PNG_BASE64).unwrap().extension(), "png");
+        assert_eq!(Base64Image::new(JPEG_BASE64).unwrap().extension(), "jpg");
+    }
 }
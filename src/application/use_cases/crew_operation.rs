@@ -1,121 +1,565 @@
 use std::sync::Arc;
 
-use crate::domain::{entities::crew_memberships::{CrewMembershipEntity, MAX_CREW_MEMBERSHIPS_PER_MISSION}, repositories::{
-    crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-    transaction_provider::TransactionProvider,
-}, value_objects::mission_statuses::MissionStatuses};
-use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
 
-pub struct CrewOperationUseCase<T1, T2, T3>
+use crate::{
+    application::use_cases::mission_authorization::{ensure_chief, mission_lookup_error},
+    domain::{
+        app_error::{AppError, AppResult},
+        entities::{
+            crew_memberships::NewCrewMembership, join_requests::NewJoinRequest,
+            mission_bans::NewMissionBan, mission_invites::NewMissionInvite,
+            missions::MissionEntity, waitlist_entries::NewWaitlistEntry,
+        },
+        repositories::{
+            crew_operation::CrewOperationRepository, transaction_provider::TransactionProvider,
+        },
+        value_objects::{
+            crew_model::CrewRoles,
+            join_policy::JoinPolicy,
+            join_request_model::{JoinRequestModel, JoinRequestStatuses},
+            mission_invite_model::{
+                CreatedMissionInviteModel, MissionInviteModel, NewMissionInviteModel,
+                RedeemMissionInviteModel,
+            },
+            waitlist_model::{CrewJoinOutcome, WaitlistPositionModel},
+        },
+    },
+    infrastructure::sha256::sha256_hex,
+};
+
+pub struct CrewOperationUseCase<T1, T2>
 where
-    T1: CrewOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: TransactionProvider + Send + Sync, // just a placeholder for the transaction provider, not necessarily.
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
 {
     crew_operation_repository: Arc<T1>,
-    mission_viewing_repository: Arc<T2>,
-    tx: Arc<T3>,
+    tx: Arc<T2>,
 }
 
-impl<T1, T2, T3> CrewOperationUseCase<T1, T2, T3>
+impl<T1, T2> CrewOperationUseCase<T1, T2>
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: TransactionProvider + Send + Sync,
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
 {
-    pub fn new(
-        crew_operation_repository: Arc<T1>,
-        mission_viewing_repository: Arc<T2>,
-        tx: Arc<T3>,
-    ) -> Self {
+    pub fn new(crew_operation_repository: Arc<T1>, tx: Arc<T2>) -> Self {
         Self {
             crew_operation_repository,
-            mission_viewing_repository,
             tx,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let mission = self
-            .mission_viewing_repository
-            .view_detail(mission_id)
-            .await?;
+    /// The mission row stays locked from the status check until the insert commits, so
+    /// two brawlers racing for the last seat cannot both get in. A full mission puts the
+    /// brawler at the back of its waitlist instead, and an approval-required mission only
+    /// records a request for the chief.
+    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> AppResult<CrewJoinOutcome> {
+        let repo = Arc::clone(&self.crew_operation_repository);
 
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
 
-        let mission_status_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        if !mission_status_condition {
-            return Err(anyhow::anyhow!("Mission is not joinable"));
-        }
+            if !mission.status.accepts_crew_changes() {
+                return Err(AppError::Conflict("Mission is not joinable".to_string()));
+            }
 
-        let crew_count_condition = crew_count < MAX_CREW_MEMBERSHIPS_PER_MISSION;
-        if !crew_count_condition {
-            return Err(anyhow::anyhow!("Mission is full"));
-        }
+            if repo.is_banned(conn, mission_id, brawler_id)? {
+                return Err(banned());
+            }
 
-        self.crew_operation_repository
-            .join(CrewMembershipEntity {
-                mission_id,
-                brawler_id,
-            })
-            .await?;
+            // The chief never needs their own approval or invite.
+            let is_chief = mission.chief_id == brawler_id;
+
+            if mission.join_policy == JoinPolicy::InviteOnly.to_string() && !is_chief {
+                return Err(AppError::Forbidden("Mission is invite-only".to_string()));
+            }
+
+            if mission.join_policy == JoinPolicy::ApprovalRequired.to_string() && !is_chief {
+                if repo.is_crew_member(conn, mission_id, brawler_id)? {
+                    return Err(AppError::Conflict("Already in the crew".to_string()));
+                }
+                if repo.has_pending_join_request(conn, mission_id, brawler_id)? {
+                    return Err(AppError::Conflict(
+                        "Join request is already pending".to_string(),
+                    ));
+                }
 
-        Ok(())
+                repo.create_join_request(
+                    conn,
+                    NewJoinRequest {
+                        mission_id,
+                        brawler_id,
+                        status: JoinRequestStatuses::Pending.to_string(),
+                    },
+                )?;
+                return Ok(CrewJoinOutcome::Requested);
+            }
+
+            seat_or_waitlist(repo.as_ref(), conn, &mission, brawler_id)
+        })
     }
 
+    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let repo = Arc::clone(&self.crew_operation_repository);
 
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
 
-    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let mission = self
-            .mission_viewing_repository
-            .view_detail(mission_id)
-            .await?;
+            if !mission.status.accepts_crew_changes() {
+                return Err(AppError::Conflict("Mission is not leavable".to_string()));
+            }
 
-        let leaving_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        if !leaving_condition {
-            return Err(anyhow::anyhow!("Mission is not leavable"));
-        }
+            let left = repo.leave(
+                conn,
+                NewCrewMembership {
+                    mission_id,
+                    brawler_id,
+                },
+            )?;
+            if left == 0 {
+                return Err(not_in_crew());
+            }
+
+            promote_from_waitlist(repo.as_ref(), conn, &mission)
+        })
+    }
+
+    /// Lets the chief remove a crew member, and with `ban` keep them from coming back
+    /// through a join, a join request or an invite.
+    pub async fn kick(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        chief_id: i32,
+        ban: bool,
+    ) -> AppResult<()> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            if brawler_id == chief_id {
+                return Err(AppError::Validation(
+                    "The chief cannot remove themselves".to_string(),
+                ));
+            }
+
+            if !mission.status.accepts_crew_changes() {
+                return Err(AppError::Conflict(
+                    "Mission crew can no longer change".to_string(),
+                ));
+            }
+
+            let is_member = repo.is_crew_member(conn, mission_id, brawler_id)?;
+            if !is_member && !ban {
+                return Err(not_in_crew());
+            }
+
+            if ban {
+                repo.ban(
+                    conn,
+                    NewMissionBan {
+                        mission_id,
+                        brawler_id,
+                        banned_by: chief_id,
+                    },
+                )?;
+                repo.leave_waitlist(conn, mission_id, brawler_id)?;
+            }
+
+            // Banning someone who was only waiting frees no seat.
+            if is_member {
+                repo.leave(
+                    conn,
+                    NewCrewMembership {
+                        mission_id,
+                        brawler_id,
+                    },
+                )?;
+                promote_from_waitlist(repo.as_ref(), conn, &mission)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Hands the mission to a crew member under the same lock as joins and leaves. The
+    /// crew itself does not change: the new chief keeps their seat as a plain member, and
+    /// the old chief stays in the crew only if they had joined it.
+    pub async fn transfer_chief(
+        &self,
+        mission_id: i32,
+        new_chief_id: i32,
+        chief_id: i32,
+    ) -> AppResult<()> {
+        let repo = Arc::clone(&self.crew_operation_repository);
 
-        self.crew_operation_repository
-            .leave(CrewMembershipEntity {
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            if !repo.is_crew_member(conn, mission_id, new_chief_id)? {
+                return Err(not_in_crew());
+            }
+
+            repo.set_chief(conn, mission_id, new_chief_id)?;
+            repo.set_crew_role(conn, mission_id, new_chief_id, CrewRoles::Member.to_string())?;
+
+            Ok(())
+        })
+    }
+
+    pub async fn waitlist_position(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> AppResult<WaitlistPositionModel> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let position = repo
+                .waitlist_position(conn, mission_id, brawler_id)?
+                .ok_or_else(|| AppError::NotFound("Not on the waitlist".to_string()))?;
+            let waitlist_size = repo.waitlist_size(conn, mission_id)?;
+
+            Ok(WaitlistPositionModel {
                 mission_id,
-                brawler_id,
+                position,
+                waitlist_size,
             })
-            .await?;
+        })
+    }
 
-        Ok(())
+    pub async fn leave_waitlist(&self, mission_id: i32, brawler_id: i32) -> AppResult<()> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            if repo.leave_waitlist(conn, mission_id, brawler_id)? == 0 {
+                return Err(AppError::NotFound("Not on the waitlist".to_string()));
+            }
+
+            Ok(())
+        })
     }
 
+    pub async fn join_requests(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> AppResult<Vec<JoinRequestModel>> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            let join_requests = repo
+                .pending_join_requests(conn, mission_id)?
+                .iter()
+                .map(|(join_request, brawler)| join_request.to_model(brawler))
+                .collect();
 
-    pub async fn join_and_delete_transaction(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let tx = Arc::clone(&self.tx);
+            Ok(join_requests)
+        })
+    }
+
+    /// Seats the requester under the same rules as an open join, so approving into a
+    /// full mission puts them on the waitlist.
+    pub async fn approve_join_request(
+        &self,
+        mission_id: i32,
+        request_id: i32,
+        chief_id: i32,
+    ) -> AppResult<CrewJoinOutcome> {
         let repo = Arc::clone(&self.crew_operation_repository);
 
-        tx.transaction::<_, anyhow::Error, _>(move |conn| {
-             repo.for_insert_transaction_test(
-                conn,
-                CrewMembershipEntity {
-                    mission_id,
-                    brawler_id,
-                },
-            )?;
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            let join_request = repo
+                .pending_join_request(conn, mission_id, request_id)?
+                .ok_or_else(|| AppError::NotFound("Join request not found".to_string()))?;
+
+            if !mission.status.accepts_crew_changes() {
+                return Err(AppError::Conflict("Mission is not joinable".to_string()));
+            }
 
-            repo.for_delete_transaction_test(
+            if repo.is_banned(conn, mission_id, join_request.brawler_id)? {
+                return Err(banned());
+            }
+
+            repo.decide_join_request(conn, request_id, JoinRequestStatuses::Approved.to_string())?;
+
+            seat_or_waitlist(repo.as_ref(), conn, &mission, join_request.brawler_id)
+        })
+    }
+
+    pub async fn reject_join_request(
+        &self,
+        mission_id: i32,
+        request_id: i32,
+        chief_id: i32,
+    ) -> AppResult<()> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            repo.pending_join_request(conn, mission_id, request_id)?
+                .ok_or_else(|| AppError::NotFound("Join request not found".to_string()))?;
+
+            repo.decide_join_request(conn, request_id, JoinRequestStatuses::Rejected.to_string())?;
+
+            Ok(())
+        })
+    }
+
+    pub async fn create_invite(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        new_mission_invite_model: NewMissionInviteModel,
+    ) -> AppResult<CreatedMissionInviteModel> {
+        if let Some(max_uses) = new_mission_invite_model.max_uses
+            && max_uses < 1
+        {
+            return Err(AppError::Validation(
+                "Invite must allow at least one use".to_string(),
+            ));
+        }
+        if let Some(expires_at) = new_mission_invite_model.expires_at
+            && expires_at <= Utc::now()
+        {
+            return Err(AppError::Validation(
+                "Invite cannot expire in the past".to_string(),
+            ));
+        }
+
+        let repo = Arc::clone(&self.crew_operation_repository);
+        let token = Uuid::new_v4().simple().to_string();
+        let token_hash = sha256_hex(&token);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            let invite = repo.create_invite(
                 conn,
-                CrewMembershipEntity {
+                NewMissionInvite {
                     mission_id,
-                    brawler_id,
+                    created_by: chief_id,
+                    token_hash,
+                    expires_at: new_mission_invite_model
+                        .expires_at
+                        .map(|expires_at| expires_at.naive_utc()),
+                    max_uses: new_mission_invite_model.max_uses,
                 },
             )?;
-            
+
+            Ok(CreatedMissionInviteModel {
+                invite: invite.to_model(),
+                token,
+            })
+        })
+    }
+
+    pub async fn invites(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+    ) -> AppResult<Vec<MissionInviteModel>> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            let invites = repo
+                .active_invites(conn, mission_id)?
+                .iter()
+                .map(|invite| invite.to_model())
+                .collect();
+
+            Ok(invites)
+        })
+    }
+
+    pub async fn revoke_invite(
+        &self,
+        mission_id: i32,
+        invite_id: i32,
+        chief_id: i32,
+    ) -> AppResult<()> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let mission = repo
+                .lock_mission(conn, mission_id)
+                .map_err(mission_lookup_error)?;
+            ensure_chief(&mission, chief_id)?;
+
+            if repo.revoke_invite(conn, mission_id, invite_id)? == 0 {
+                return Err(AppError::NotFound("Invite not found".to_string()));
+            }
+
             Ok(())
         })
     }
 
+    /// An invite stands in for the chief's approval, so it works on approval-required and
+    /// invite-only missions alike. Seating still follows the capacity rules of `join`.
+    ///
+    /// Landing on the waitlist uses up the invite just like getting a seat does: a later
+    /// promotion from the waitlist does not ask for the invite again, so not counting it
+    /// would let a single-use invite admit any number of brawlers.
+    pub async fn redeem_invite(
+        &self,
+        brawler_id: i32,
+        redeem_mission_invite_model: RedeemMissionInviteModel,
+    ) -> AppResult<(i32, CrewJoinOutcome)> {
+        let repo = Arc::clone(&self.crew_operation_repository);
+        let token_hash = sha256_hex(redeem_mission_invite_model.token.trim());
+
+        self.tx.transaction::<_, AppError, _>(move |conn| {
+            let invalid_invite = || AppError::Validation("Invalid or expired invite".to_string());
+
+            let invite = repo
+                .usable_invite(conn, token_hash)?
+                .ok_or_else(invalid_invite)?;
+
+            let mission = repo
+                .lock_mission(conn, invite.mission_id)
+                .map_err(mission_lookup_error)?;
+
+            if !mission.status.accepts_crew_changes() {
+                return Err(AppError::Conflict("Mission is not joinable".to_string()));
+            }
+
+            if repo.is_banned(conn, mission.id, brawler_id)? {
+                return Err(banned());
+            }
+
+            let outcome = seat_or_waitlist(repo.as_ref(), conn, &mission, brawler_id)?;
+
+            // Two redemptions of the last use can both get this far; only one may win.
+            if !repo.consume_invite(conn, invite.id)? {
+                return Err(invalid_invite());
+            }
+
+            Ok((mission.id, outcome))
+        })
+    }
+}
+
+/// Gives the brawler a seat if there is one, otherwise a place at the back of the
+/// waitlist. Callers must hold the mission lock.
+fn seat_or_waitlist<T>(
+    repo: &T,
+    conn: &mut T::Connection,
+    mission: &MissionEntity,
+    brawler_id: i32,
+) -> AppResult<CrewJoinOutcome>
+where
+    T: CrewOperationRepository + ?Sized,
+{
+    let mission_id = mission.id;
+
+    if repo.is_crew_member(conn, mission_id, brawler_id)? {
+        return Err(AppError::Conflict("Already in the crew".to_string()));
+    }
+
+    let crew_count = repo.crew_counting(conn, mission_id)?;
+    if mission.has_room_for_more_crew(crew_count) {
+        repo.join(
+            conn,
+            NewCrewMembership {
+                mission_id,
+                brawler_id,
+            },
+        )?;
+        // A seat opened up without a promotion (e.g. the chief raised max_crew).
+        repo.leave_waitlist(conn, mission_id, brawler_id)?;
+        return Ok(CrewJoinOutcome::Joined);
+    }
+
+    if repo
+        .waitlist_position(conn, mission_id, brawler_id)?
+        .is_some()
+    {
+        return Err(AppError::Conflict("Already on the waitlist".to_string()));
+    }
+
+    repo.join_waitlist(
+        conn,
+        NewWaitlistEntry {
+            mission_id,
+            brawler_id,
+        },
+    )?;
+    let position = repo
+        .waitlist_position(conn, mission_id, brawler_id)?
+        .ok_or_else(|| anyhow::anyhow!("Waitlist entry vanished after insert"))?;
+
+    Ok(CrewJoinOutcome::Waitlisted { position })
 }
 
+/// Fills free seats from the head of the waitlist. Callers must hold the mission lock,
+/// so a freed seat cannot be taken by a concurrent join before the queue gets it.
+fn promote_from_waitlist<T>(
+    repo: &T,
+    conn: &mut T::Connection,
+    mission: &MissionEntity,
+) -> AppResult<()>
+where
+    T: CrewOperationRepository + ?Sized,
+{
+    let mission_id = mission.id;
+
+    let mut crew_count = repo.crew_counting(conn, mission_id)?;
+    while mission.has_room_for_more_crew(crew_count) {
+        let Some(next) = repo.waitlist_head(conn, mission_id)? else {
+            break;
+        };
+        repo.leave_waitlist(conn, mission_id, next.brawler_id)?;
+        repo.join(
+            conn,
+            NewCrewMembership {
+                mission_id,
+                brawler_id: next.brawler_id,
+            },
+        )?;
+        crew_count += 1;
+    }
+
+    Ok(())
+}
+
+fn not_in_crew() -> AppError {
+    AppError::NotFound("Brawler is not in the crew".to_string())
+}
+
+fn banned() -> AppError {
+    AppError::Forbidden("Banned from this mission".to_string())
+}
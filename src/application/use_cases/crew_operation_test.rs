@@ -3,255 +3,998 @@ mod tests {
     use std::sync::Arc;
 
     use chrono::{TimeZone, Utc};
-    use diesel::{PgConnection, r2d2::{ConnectionManager, PooledConnection}};
-
-    use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::{entities::{crew_memberships::MAX_CREW_MEMBERSHIPS_PER_MISSION, missions::MissionEntity}, repositories::{crew_operation::MockCrewOperationRepository, mission_viewing::MockMissionViewingRepository, transaction_provider::MockTransactionProvider}, value_objects::mission_statuses::MissionStatuses}, infrastructure::database::postgresql_connection::establish_connection};
-
-    #[tokio::test]
-    async fn test_join_success() {
-        let mut mock_crew_repo = MockCrewOperationRepository::new();
-        let mut mock_mission_repo = MockMissionViewingRepository::new();
-        let mock_tx_repo = MockTransactionProvider::new();
+    use crate::{
+        application::use_cases::crew_operation::CrewOperationUseCase,
+        domain::{
+            app_error::AppError,
+            entities::{
+                join_requests::JoinRequestEntity, mission_invites::MissionInviteEntity,
+                missions::MissionEntity, waitlist_entries::WaitlistEntryEntity,
+            },
+            repositories::{
+                crew_operation::MockCrewOperationRepository,
+                transaction_provider::TransactionProvider,
+            },
+            value_objects::{
+                crew_model::CrewRoles,
+                join_policy::JoinPolicy,
+                join_request_model::JoinRequestStatuses,
+                mission_invite_model::{NewMissionInviteModel, RedeemMissionInviteModel},
+                mission_statuses::MissionStatuses,
+                waitlist_model::CrewJoinOutcome,
+            },
+        },
+        infrastructure::sha256::sha256_hex,
+    };
 
+    fn mission(status: MissionStatuses, max_crew: i32) -> MissionEntity {
         let now = Utc
             .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
             .unwrap()
             .naive_utc();
+        MissionEntity {
+            id: 1,
+            name: "test mission".to_string(),
+            description: Some("test".to_string()),
+            status,
+            chief_id: 1,
+            created_at: now,
+            updated_at: now,
+            starts_at: now,
+            ends_at: now + chrono::Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue_name: None,
+            venue_address: None,
+            latitude: None,
+            longitude: None,
+            max_crew,
+            min_crew: 1,
+            join_policy: JoinPolicy::Open.to_string(),
+        }
+    }
+
+    fn mission_with_policy(join_policy: JoinPolicy, max_crew: i32) -> MissionEntity {
+        MissionEntity {
+            join_policy: join_policy.to_string(),
+            ..mission(MissionStatuses::Open, max_crew)
+        }
+    }
+
+    fn invite(token: &str) -> MissionInviteEntity {
+        MissionInviteEntity {
+            id: 4,
+            mission_id: 1,
+            created_by: 1,
+            token_hash: sha256_hex(token),
+            created_at: Utc::now().naive_utc(),
+            expires_at: None,
+            max_uses: Some(1),
+            uses: 0,
+            revoked_at: None,
+        }
+    }
 
-        mock_mission_repo
+    fn join_request(brawler_id: i32) -> JoinRequestEntity {
+        JoinRequestEntity {
+            id: 3,
+            mission_id: 1,
+            brawler_id,
+            status: JoinRequestStatuses::Pending.to_string(),
+            created_at: Utc::now().naive_utc(),
+            decided_at: None,
+        }
+    }
+
+    // Runs the closure straight away; every repository call on the connection is mocked,
+    // so there is nothing to connect to.
+    struct NoDbTransaction;
+
+    impl TransactionProvider for NoDbTransaction {
+        type Connection = ();
+
+        fn transaction<T, E, F>(&self, f: F) -> Result<T, E>
+        where
+            F: FnOnce(&mut ()) -> Result<T, E> + 'static,
+            T: 'static,
+            E: From<anyhow::Error> + From<diesel::result::Error> + 'static,
+        {
+            f(&mut ())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_join_success() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
             .expect_crew_counting()
-            .returning(|_| Box::pin(async { Ok(2) }));
-        mock_mission_repo.expect_view_detail().returning(move |_| {
-            Box::pin(async move {
-                Ok(MissionEntity {
-                    id: 1,
-                    name: "test mission".to_string(),
-                    description: Some("test".to_string()),
-                    status: MissionStatuses::Open.to_string(),
-                    chief_id: 1,
-                    created_at: now,
-                    updated_at: now,
-                })
-            })
-        });
+            .returning(|_, _| Ok(2));
         mock_crew_repo
             .expect_join()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .withf(|_, crew| crew.mission_id == 1 && crew.brawler_id == 2)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .returning(|_, _, _| Ok(0));
+        mock_crew_repo.expect_join_waitlist().never();
 
         let use_case = CrewOperationUseCase::new(
             Arc::new(mock_crew_repo),
-            Arc::new(mock_mission_repo),
-            Arc::new(mock_tx_repo),
+            Arc::new(NoDbTransaction),
         );
 
-        let result = use_case.join(1, 1).await;
+        let result = use_case.join(1, 2).await;
 
-        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), CrewJoinOutcome::Joined);
     }
 
     #[tokio::test]
     async fn test_join_fails_when_mission_is_not_open() {
-        #[allow(unused_mut)]
         let mut mock_crew_repo = MockCrewOperationRepository::new();
-        let mut mock_mission_repo = MockMissionViewingRepository::new();
-        let mock_tx_repo = MockTransactionProvider::new();
 
-        let now = Utc
-            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
-            .unwrap()
-            .naive_utc();
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::InProgress, 10)));
+        mock_crew_repo.expect_join().never();
 
-        mock_mission_repo
-            .expect_crew_counting()
-            .returning(|_| Box::pin(async { Ok(2) }));
-        mock_mission_repo.expect_view_detail().returning(move |_| {
-            Box::pin(async move {
-                Ok(MissionEntity {
-                    id: 1,
-                    name: "test mission".to_string(),
-                    description: Some("test".to_string()),
-                    status: MissionStatuses::InProgress.to_string(),
-                    chief_id: 1,
-                    created_at: now,
-                    updated_at: now,
-                })
-            })
-        });
         let use_case = CrewOperationUseCase::new(
             Arc::new(mock_crew_repo),
-            Arc::new(mock_mission_repo),
-            Arc::new(mock_tx_repo),
+            Arc::new(NoDbTransaction),
         );
 
         let result = use_case.join(1, 1).await;
 
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().to_string(), "Mission is not joinable");
-
     }
 
-
     #[tokio::test]
-    async fn test_join_fails_when_mission_is_full() {
-        #[allow(unused_mut)]
+    async fn test_join_full_mission_goes_to_waitlist() {
         let mut mock_crew_repo = MockCrewOperationRepository::new();
-        let mut mock_mission_repo = MockMissionViewingRepository::new();
-        let mock_tx_repo = MockTransactionProvider::new();
 
-        let now = Utc
-            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
-            .unwrap()
-            .naive_utc();
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 4)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_crew_counting()
+            .returning(|_, _| Ok(4));
+        mock_crew_repo.expect_join().never();
+        let mut position_lookups = 0;
+        mock_crew_repo
+            .expect_waitlist_position()
+            .returning(move |_, _, _| {
+                position_lookups += 1;
+                Ok((position_lookups > 1).then_some(3))
+            });
+        mock_crew_repo
+            .expect_join_waitlist()
+            .withf(|_, entry| entry.mission_id == 1 && entry.brawler_id == 5)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case.join(1, 5).await;
 
-        mock_mission_repo
+        assert_eq!(result.unwrap(), CrewJoinOutcome::Waitlisted { position: 3 });
+    }
+
+    #[tokio::test]
+    async fn test_join_full_mission_twice_is_conflict() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 4)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
             .expect_crew_counting()
-            .returning(|_| Box::pin(async { Ok(MAX_CREW_MEMBERSHIPS_PER_MISSION as u32) }));
-
-        mock_mission_repo.expect_view_detail().returning(move |_| {
-            Box::pin(async move {
-                Ok(MissionEntity {
-                    id: 1,
-                    name: "test mission".to_string(),
-                    description: Some("test".to_string()),
-                    status: MissionStatuses::Open.to_string(),
-                    chief_id: 1,
-                    created_at: now,
-                    updated_at: now,
-                })
-            })
-        });
+            .returning(|_, _| Ok(4));
+        mock_crew_repo
+            .expect_waitlist_position()
+            .returning(|_, _, _| Ok(Some(1)));
+        mock_crew_repo.expect_join_waitlist().never();
 
         let use_case = CrewOperationUseCase::new(
             Arc::new(mock_crew_repo),
-            Arc::new(mock_mission_repo),
-            Arc::new(mock_tx_repo),
+            Arc::new(NoDbTransaction),
         );
 
-        let result = use_case.join(1, 1).await;
+        let result = use_case.join(1, 5).await;
 
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err().to_string(), "Mission is full");
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        assert_eq!(result.unwrap_err().to_string(), "Already on the waitlist");
+    }
+
+    #[tokio::test]
+    async fn test_join_as_crew_member_is_conflict() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
 
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(true));
+        mock_crew_repo.expect_join().never();
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case.join(1, 5).await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
     }
 
     #[tokio::test]
-    async fn test_leave_success() {
+    async fn test_join_missing_mission_is_not_found() {
         let mut mock_crew_repo = MockCrewOperationRepository::new();
-        let mut mock_mission_repo = MockMissionViewingRepository::new();
-        let mock_tx_repo = MockTransactionProvider::new();
 
-        let now = Utc
-            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
-            .unwrap()
-            .naive_utc();
-        mock_mission_repo.expect_view_detail().returning(move |_| {
-            Box::pin(async move {
-                Ok(MissionEntity {
-                    id: 1,
-                    name: "test mission".to_string(),
-                    description: Some("test".to_string()),
-                    status: MissionStatuses::Open.to_string(),
-                    chief_id: 1,
-                    created_at: now,
-                    updated_at: now,
-                })
-            })
-        });
         mock_crew_repo
-            .expect_leave()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .expect_lock_mission()
+            .returning(|_, _| Err(anyhow::Error::from(diesel::result::Error::NotFound)));
 
         let use_case = CrewOperationUseCase::new(
             Arc::new(mock_crew_repo),
-            Arc::new(mock_mission_repo),
-            Arc::new(mock_tx_repo),
+            Arc::new(NoDbTransaction),
         );
 
+        let result = use_case.join(99, 1).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+        assert_eq!(result.unwrap_err().to_string(), "Mission not found");
+    }
+
+    #[tokio::test]
+    async fn test_leave_success() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_leave()
+            .times(1)
+            .returning(|_, _| Ok(1));
+        mock_crew_repo
+            .expect_crew_counting()
+            .returning(|_, _| Ok(3));
+        mock_crew_repo
+            .expect_waitlist_head()
+            .returning(|_, _| Ok(None));
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
         let result = use_case.leave(1, 1).await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_leave_by_non_member_is_not_found() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_leave()
+            .times(1)
+            .returning(|_, _| Ok(0));
+        mock_crew_repo.expect_waitlist_head().never();
+        mock_crew_repo.expect_join().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.leave(1, 9).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
 
     #[tokio::test]
     async fn test_leave_fails_when_mission_is_not_open() {
         let mut mock_crew_repo = MockCrewOperationRepository::new();
-        let mut mock_mission_repo = MockMissionViewingRepository::new();
-        let mock_tx_repo = MockTransactionProvider::new();
 
-        let now = Utc
-            .with_ymd_and_hms(1970, 1, 1, 0, 0, 0)
-            .unwrap()
-            .naive_utc();
-        mock_mission_repo.expect_view_detail().returning(move |_| {
-            Box::pin(async move {
-                Ok(MissionEntity {
-                    id: 1,
-                    name: "test mission".to_string(),
-                    description: Some("test".to_string()),
-                    status: MissionStatuses::InProgress.to_string(),
-                    chief_id: 1,
-                    created_at: now,
-                    updated_at: now,
-                })
-            })
-        });
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::InProgress, 10)));
+        mock_crew_repo.expect_leave().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.leave(1, 1).await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Mission is not leavable")
+    }
+
+    #[tokio::test]
+    async fn test_leave_promotes_head_of_waitlist() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 4)));
         mock_crew_repo
             .expect_leave()
-            .returning(|_| Box::pin(async { Ok(()) }));
+            .withf(|_, crew| crew.brawler_id == 2)
+            .times(1)
+            .returning(|_, _| Ok(1));
+        mock_crew_repo
+            .expect_crew_counting()
+            .returning(|_, _| Ok(3));
+        mock_crew_repo
+            .expect_waitlist_head()
+            .times(1)
+            .returning(|_, _| {
+                Ok(Some(WaitlistEntryEntity {
+                    id: 11,
+                    mission_id: 1,
+                    brawler_id: 7,
+                    joined_at: Utc::now().naive_utc(),
+                }))
+            });
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .withf(|_, mission_id, brawler_id| *mission_id == 1 && *brawler_id == 7)
+            .times(1)
+            .returning(|_, _, _| Ok(1));
+        mock_crew_repo
+            .expect_join()
+            .withf(|_, crew| crew.mission_id == 1 && crew.brawler_id == 7)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.leave(1, 2).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_waitlist_position_when_not_waiting_is_not_found() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_waitlist_position()
+            .returning(|_, _, _| Ok(None));
 
         let use_case = CrewOperationUseCase::new(
             Arc::new(mock_crew_repo),
-            Arc::new(mock_mission_repo),
-            Arc::new(mock_tx_repo),
+            Arc::new(NoDbTransaction),
         );
 
-        let result = use_case.leave(1, 1).await;
+        let result = use_case.waitlist_position(1, 5).await;
 
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err().to_string(), "Mission is not leavable")
+        assert!(matches!(result, Err(AppError::NotFound(_))));
     }
 
+    #[tokio::test]
+    async fn test_leave_waitlist_when_not_waiting_is_not_found() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .returning(|_, _, _| Ok(0));
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.leave_waitlist(1, 5).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
 
-    // This is need a real database connection to test the transaction.
     #[tokio::test]
-    async fn test_insert_and_delete_transaction() {
+    async fn test_join_approval_required_mission_files_request() {
         let mut mock_crew_repo = MockCrewOperationRepository::new();
-        let mock_mission_repo = MockMissionViewingRepository::new();
-        let mut mock_tx_repo = MockTransactionProvider::new();
-
-        mock_tx_repo.expect_transaction().returning(
-            |f: Box<
-                dyn FnOnce(
-                        &mut PooledConnection<ConnectionManager<PgConnection>>,
-                    ) -> Result<(), anyhow::Error>
-                    + 'static,
-            >| {
-                dotenvy::dotenv().ok();
-                let db_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is invalid");
-                let db_pool = establish_connection(&db_url).unwrap();
-                let mut conn = db_pool.get().unwrap();
-                f(&mut conn)
-            },
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::ApprovalRequired, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_has_pending_join_request()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_create_join_request()
+            .withf(|_, request| request.brawler_id == 5 && request.status == "Pending")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_crew_repo.expect_join().never();
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
         );
+
+        let result = use_case.join(1, 5).await;
+
+        assert_eq!(result.unwrap(), CrewJoinOutcome::Requested);
+    }
+
+    #[tokio::test]
+    async fn test_join_invite_only_mission_is_forbidden() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::InviteOnly, 10)));
+        mock_crew_repo.expect_join().never();
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case.join(1, 5).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_approve_join_request_into_full_mission_waitlists() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::ApprovalRequired, 4)));
+        mock_crew_repo
+            .expect_pending_join_request()
+            .returning(|_, _, _| Ok(Some(join_request(5))));
+        mock_crew_repo
+            .expect_decide_join_request()
+            .withf(|_, request_id, status| *request_id == 3 && status == "Approved")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_crew_counting()
+            .returning(|_, _| Ok(4));
+        mock_crew_repo.expect_join().never();
+        let mut position_lookups = 0;
+        mock_crew_repo
+            .expect_waitlist_position()
+            .returning(move |_, _, _| {
+                position_lookups += 1;
+                Ok((position_lookups > 1).then_some(1))
+            });
         mock_crew_repo
-            .expect_for_insert_transaction_test()
+            .expect_join_waitlist()
+            .withf(|_, entry| entry.brawler_id == 5)
+            .times(1)
             .returning(|_, _| Ok(()));
 
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case.approve_join_request(1, 3, 1).await;
+
+        assert_eq!(result.unwrap(), CrewJoinOutcome::Waitlisted { position: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_approve_join_request_by_non_chief_is_forbidden() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::ApprovalRequired, 10)));
+        mock_crew_repo.expect_decide_join_request().never();
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case.approve_join_request(1, 3, 2).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reject_unknown_join_request_is_not_found() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::ApprovalRequired, 10)));
+        mock_crew_repo
+            .expect_pending_join_request()
+            .returning(|_, _, _| Ok(None));
+        mock_crew_repo.expect_decide_join_request().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.reject_join_request(1, 99, 1).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_invite_rejects_invalid_limits() {
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(MockCrewOperationRepository::new()),
+            Arc::new(NoDbTransaction),
+        );
+
+        let no_uses = use_case
+            .create_invite(
+                1,
+                1,
+                NewMissionInviteModel {
+                    max_uses: Some(0),
+                    ..Default::default()
+                },
+            )
+            .await;
+        let already_expired = use_case
+            .create_invite(
+                1,
+                1,
+                NewMissionInviteModel {
+                    expires_at: Some(Utc::now() - chrono::Duration::minutes(1)),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        assert!(matches!(no_uses, Err(AppError::Validation(_))));
+        assert!(matches!(already_expired, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_invite_joins_invite_only_mission() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_usable_invite()
+            .withf(|_, token_hash| *token_hash == sha256_hex("abc123"))
+            .returning(|_, _| Ok(Some(invite("abc123"))));
+        mock_crew_repo
+            .expect_lock_mission()
+            .withf(|_, mission_id| *mission_id == 1)
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::InviteOnly, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_crew_counting()
+            .returning(|_, _| Ok(2));
+        mock_crew_repo
+            .expect_join()
+            .withf(|_, crew| crew.brawler_id == 5)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .returning(|_, _, _| Ok(0));
+        mock_crew_repo
+            .expect_consume_invite()
+            .withf(|_, invite_id| *invite_id == 4)
+            .times(1)
+            .returning(|_, _| Ok(true));
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case
+            .redeem_invite(
+                5,
+                RedeemMissionInviteModel {
+                    token: " abc123 ".to_string(),
+                },
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), (1, CrewJoinOutcome::Joined));
+    }
+
+    #[tokio::test]
+    async fn test_redeem_invite_onto_waitlist_uses_up_the_invite() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_is_banned()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_usable_invite()
+            .returning(|_, _| Ok(Some(invite("abc123"))));
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::InviteOnly, 2)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
         mock_crew_repo
-            .expect_for_delete_transaction_test()
+            .expect_crew_counting()
+            .returning(|_, _| Ok(2));
+        mock_crew_repo.expect_join().never();
+        mock_crew_repo
+            .expect_waitlist_position()
+            .returning({
+                let mut queued = false;
+                move |_, _, _| {
+                    let position = queued.then_some(3);
+                    queued = true;
+                    Ok(position)
+                }
+            });
+        mock_crew_repo
+            .expect_join_waitlist()
+            .withf(|_, entry| entry.brawler_id == 5)
+            .times(1)
             .returning(|_, _| Ok(()));
+        mock_crew_repo
+            .expect_consume_invite()
+            .withf(|_, invite_id| *invite_id == 4)
+            .times(1)
+            .returning(|_, _| Ok(true));
 
         let use_case = CrewOperationUseCase::new(
             Arc::new(mock_crew_repo),
-            Arc::new(mock_mission_repo),
-            Arc::new(mock_tx_repo),
+            Arc::new(NoDbTransaction),
         );
 
-        let result = use_case.join_and_delete_transaction(1, 1).await;
+        let result = use_case
+            .redeem_invite(
+                5,
+                RedeemMissionInviteModel {
+                    token: "abc123".to_string(),
+                },
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap(),
+            (1, CrewJoinOutcome::Waitlisted { position: 3 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_redeem_unknown_invite_is_rejected() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_usable_invite()
+            .returning(|_, _| Ok(None));
+        mock_crew_repo.expect_join().never();
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case
+            .redeem_invite(
+                5,
+                RedeemMissionInviteModel {
+                    token: "nope".to_string(),
+                },
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_unknown_invite_is_not_found() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission_with_policy(JoinPolicy::InviteOnly, 10)));
+        mock_crew_repo
+            .expect_revoke_invite()
+            .returning(|_, _, _| Ok(0));
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.revoke_invite(1, 99, 1).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_join_banned_brawler_is_forbidden() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_banned()
+            .withf(|_, mission_id, brawler_id| *mission_id == 1 && *brawler_id == 5)
+            .returning(|_, _, _| Ok(true));
+        mock_crew_repo.expect_join().never();
+        mock_crew_repo.expect_join_waitlist().never();
+
+        let use_case = CrewOperationUseCase::new(
+            Arc::new(mock_crew_repo),
+            Arc::new(NoDbTransaction),
+        );
+
+        let result = use_case.join(1, 5).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_kick_with_ban_removes_member_and_promotes_waitlist() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 4)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(true));
+        mock_crew_repo
+            .expect_ban()
+            .withf(|_, ban| ban.mission_id == 1 && ban.brawler_id == 5 && ban.banned_by == 1)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .withf(|_, _, brawler_id| *brawler_id == 5)
+            .times(1)
+            .returning(|_, _, _| Ok(0));
+        mock_crew_repo
+            .expect_leave()
+            .withf(|_, crew| crew.brawler_id == 5)
+            .times(1)
+            .returning(|_, _| Ok(1));
+        mock_crew_repo
+            .expect_crew_counting()
+            .returning(|_, _| Ok(3));
+        mock_crew_repo.expect_waitlist_head().returning(|_, _| {
+            Ok(Some(WaitlistEntryEntity {
+                id: 11,
+                mission_id: 1,
+                brawler_id: 7,
+                joined_at: Utc::now().naive_utc(),
+            }))
+        });
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .withf(|_, _, brawler_id| *brawler_id == 7)
+            .times(1)
+            .returning(|_, _, _| Ok(1));
+        mock_crew_repo
+            .expect_join()
+            .withf(|_, crew| crew.brawler_id == 7)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.kick(1, 5, 1, true).await;
 
         assert!(result.is_ok());
     }
 
-            
+    #[tokio::test]
+    async fn test_kick_non_member_without_ban_is_not_found() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo.expect_leave().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.kick(1, 5, 1, false).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_ban_of_non_member_does_not_promote_waitlist() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo
+            .expect_ban()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_crew_repo
+            .expect_leave_waitlist()
+            .withf(|_, _, brawler_id| *brawler_id == 5)
+            .times(1)
+            .returning(|_, _, _| Ok(1));
+        mock_crew_repo.expect_leave().never();
+        mock_crew_repo.expect_waitlist_head().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.kick(1, 5, 1, true).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_kick_by_non_chief_is_forbidden() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo.expect_leave().never();
+        mock_crew_repo.expect_ban().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.kick(1, 5, 2, true).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_chief() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .withf(|_, _, brawler_id| *brawler_id == 10)
+            .returning(|_, _, _| Ok(true));
+        mock_crew_repo
+            .expect_set_chief()
+            .withf(|_, mission_id, chief_id| *mission_id == 1 && *chief_id == 10)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_crew_repo
+            .expect_set_crew_role()
+            .withf(|_, _, brawler_id, role| {
+                *brawler_id == 10 && role == &CrewRoles::Member.to_string()
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.transfer_chief(1, 10, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_chief_when_old_chief_is_in_crew() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        // The old chief (1) joined their own crew, which is now full and in progress.
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::InProgress, 2)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(true));
+        mock_crew_repo
+            .expect_set_chief()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_crew_repo
+            .expect_set_crew_role()
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        mock_crew_repo.expect_leave().never();
+        mock_crew_repo.expect_join().never();
+        mock_crew_repo.expect_waitlist_head().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.transfer_chief(1, 10, 1).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_chief_to_non_member() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo
+            .expect_is_crew_member()
+            .returning(|_, _, _| Ok(false));
+        mock_crew_repo.expect_set_chief().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.transfer_chief(1, 10, 1).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_transfer_chief_by_non_chief() {
+        let mut mock_crew_repo = MockCrewOperationRepository::new();
+
+        mock_crew_repo
+            .expect_lock_mission()
+            .returning(|_, _| Ok(mission(MissionStatuses::Open, 10)));
+        mock_crew_repo.expect_set_chief().never();
+
+        let use_case =
+            CrewOperationUseCase::new(Arc::new(mock_crew_repo), Arc::new(NoDbTransaction));
+
+        let result = use_case.transfer_chief(1, 10, 2).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
 }
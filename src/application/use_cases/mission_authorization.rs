@@ -0,0 +1,152 @@
+use crate::{
+    domain::{
+        app_error::{AppError, AppResult},
+        entities::missions::MissionEntity,
+        repositories::mission_viewing::MissionViewingRepository,
+        value_objects::{
+            crew_model::CrewRoles, join_policy::JoinPolicy, mission_statuses::MissionStatuses,
+            mission_viewer::MissionViewer,
+        },
+    },
+    infrastructure::sha256::sha256_hex,
+};
+
+/// Loads a mission that has not been removed.
+pub async fn find_mission<T>(mission_viewing_repository: &T, mission_id: i32) -> AppResult<MissionEntity>
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    mission_viewing_repository
+        .view_detail(mission_id)
+        .await
+        .map_err(mission_lookup_error)
+}
+
+/// Loads a mission `viewer` may look at. Drafts are only shown to their chief and
+/// co-chiefs. Invite-only missions stay hidden unless the viewer is signed in and is
+/// its chief, in its crew or on its waitlist, or brings one of its invites. Everyone
+/// else is told the mission does not exist.
+pub async fn find_visible_mission<T>(
+    mission_viewing_repository: &T,
+    mission_id: i32,
+    viewer: &MissionViewer,
+) -> AppResult<MissionEntity>
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    let mission = find_mission(mission_viewing_repository, mission_id).await?;
+
+    if mission.status == MissionStatuses::Draft {
+        let is_leader = match viewer.brawler_id {
+            Some(brawler_id) if brawler_id == mission.chief_id => true,
+            Some(brawler_id) => {
+                mission_viewing_repository
+                    .crew_role(mission_id, brawler_id)
+                    .await?
+                    == Some(CrewRoles::CoChief.to_string())
+            }
+            None => false,
+        };
+        if !is_leader {
+            return Err(AppError::NotFound("Mission not found".to_string()));
+        }
+
+        return Ok(mission);
+    }
+
+    if mission.join_policy != JoinPolicy::InviteOnly.to_string() {
+        return Ok(mission);
+    }
+
+    if let Some(brawler_id) = viewer.brawler_id
+        && (mission.chief_id == brawler_id
+            || mission_viewing_repository
+                .crew_role(mission_id, brawler_id)
+                .await?
+                .is_some()
+            || mission_viewing_repository
+                .is_waitlisted(mission_id, brawler_id)
+                .await?)
+    {
+        return Ok(mission);
+    }
+
+    let has_invite = match &viewer.invite_token {
+        Some(token) => {
+            mission_viewing_repository
+                .has_usable_invite(mission_id, sha256_hex(token))
+                .await?
+        }
+        None => false,
+    };
+    if !has_invite {
+        return Err(AppError::NotFound("Mission not found".to_string()));
+    }
+    if viewer.brawler_id.is_none() {
+        return Err(AppError::Unauthorized(
+            "Sign in to view an invite-only mission".to_string(),
+        ));
+    }
+
+    Ok(mission)
+}
+
+/// Reports a missing mission row as "Mission not found" rather than a generic record.
+pub fn mission_lookup_error(e: anyhow::Error) -> AppError {
+    match AppError::from(e) {
+        AppError::NotFound(_) => AppError::NotFound("Mission not found".to_string()),
+        e => e,
+    }
+}
+
+/// Loads the mission and makes sure `brawler_id` is its chief.
+pub async fn authorize_chief<T>(
+    mission_viewing_repository: &T,
+    mission_id: i32,
+    brawler_id: i32,
+) -> AppResult<MissionEntity>
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    let mission = find_mission(mission_viewing_repository, mission_id).await?;
+    ensure_chief(&mission, brawler_id)?;
+
+    Ok(mission)
+}
+
+/// Loads the mission and makes sure `brawler_id` is its chief or one of its co-chiefs.
+pub async fn authorize_leader<T>(
+    mission_viewing_repository: &T,
+    mission_id: i32,
+    brawler_id: i32,
+) -> AppResult<MissionEntity>
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    let mission = find_mission(mission_viewing_repository, mission_id).await?;
+    if mission.chief_id == brawler_id {
+        return Ok(mission);
+    }
+
+    let role = mission_viewing_repository
+        .crew_role(mission_id, brawler_id)
+        .await?;
+    if role != Some(CrewRoles::CoChief.to_string()) {
+        return Err(AppError::Forbidden(
+            "Only the chief or a co-chief can run this mission".to_string(),
+        ));
+    }
+
+    Ok(mission)
+}
+
+/// The check behind `authorize_chief`, for callers that already hold the mission.
+pub fn ensure_chief(mission: &MissionEntity, brawler_id: i32) -> AppResult<()> {
+    if mission.chief_id != brawler_id {
+        return Err(AppError::Forbidden(
+            "Only the chief can change this mission".to_string(),
+        ));
+    }
+
+    Ok(())
+}
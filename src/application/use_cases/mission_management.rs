@@ -1,12 +1,26 @@
 use std::sync::Arc;
 
-use crate::domain::{
-    repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::{
+    application::use_cases::mission_authorization::authorize_chief,
+    domain::{
+        app_error::{AppError, AppResult},
+        entities::{
+            crew_memberships::{DEFAULT_MAX_CREW, MAX_CREW_LIMIT},
+            missions::MissionEntity,
+        },
+        repositories::{
+            mission_management::MissionManagementRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            mission_model::{NewMissionModel, UpdateMissionModel},
+            tag_model::{MAX_TAGS_PER_MISSION, normalize_tags},
+        },
     },
-    value_objects::mission_model::{NewMissionModel, UpdateMissionModel},
 };
-use anyhow::Result;
 pub struct MissionManagementUseCase<T1, T2>
 where
     T1: MissionManagementRepository + Send + Sync,
@@ -31,16 +45,37 @@ where
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: NewMissionModel) -> Result<i32> {
+    pub async fn add(&self, chief_id: i32, add_mission_model: NewMissionModel) -> AppResult<i32> {
         if add_mission_model.name.len() > 255 {
-            return Err(anyhow::anyhow!("Mission name is too long (max 255 characters)"));
+            return Err(AppError::Validation(
+                "Mission name is too long (max 255 characters)".to_string(),
+            ));
+        }
+
+        validate_schedule(
+            add_mission_model.starts_at,
+            add_mission_model.ends_at,
+            &add_mission_model.timezone,
+        )?;
+        validate_capacity(
+            add_mission_model.min_crew.unwrap_or(1),
+            add_mission_model.max_crew.unwrap_or(DEFAULT_MAX_CREW),
+        )?;
+        if let Some(venue) = &add_mission_model.venue {
+            venue.validate()?;
         }
+        if add_mission_model.starts_at < Utc::now() {
+            return Err(AppError::Validation(
+                "Mission cannot start in the past".to_string(),
+            ));
+        }
+        let tags = validate_tags(&add_mission_model.tags)?;
 
         let insert_mission_entity = add_mission_model.to_entity(chief_id);
 
         let result = self
             .mission_management_repository
-            .add(insert_mission_entity)
+            .add(insert_mission_entity, tags)
             .await?;
 
         Ok(result)
@@ -51,35 +86,75 @@ where
         mission_id: i32,
         chief_id: i32,
         edit_mission_model: UpdateMissionModel,
-    ) -> Result<i32> {
+    ) -> AppResult<i32> {
+        let mission =
+            authorize_chief(self.mission_viewing_repository.as_ref(), mission_id, chief_id).await?;
+        ensure_editable(&mission)?;
+
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
             .await?;
         if crew_count > 0 {
-            return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
+            return Err(AppError::Conflict(
+                "Mission has been taken by brawler for now!".to_string(),
+            ));
+        }
+
+        // Only the fields being changed are sent, so check them against the current schedule.
+        validate_schedule(
+            edit_mission_model
+                .starts_at
+                .unwrap_or(mission.starts_at.and_utc()),
+            edit_mission_model.ends_at.unwrap_or(mission.ends_at.and_utc()),
+            edit_mission_model
+                .timezone
+                .as_deref()
+                .unwrap_or(&mission.timezone),
+        )?;
+        validate_capacity(
+            edit_mission_model.min_crew.unwrap_or(mission.min_crew),
+            edit_mission_model.max_crew.unwrap_or(mission.max_crew),
+        )?;
+        if let Some(venue) = &edit_mission_model.venue {
+            venue.validate()?;
+        }
+        if let Some(starts_at) = edit_mission_model.starts_at
+            && starts_at < Utc::now()
+        {
+            return Err(AppError::Validation(
+                "Mission cannot start in the past".to_string(),
             ));
         }
 
-        let edit_mission_entity = edit_mission_model.to_entity(chief_id);
+        let tags = edit_mission_model
+            .tags
+            .as_deref()
+            .map(validate_tags)
+            .transpose()?;
+
+        let edit_mission_entity = edit_mission_model.to_entity();
 
         let result = self
             .mission_management_repository
-            .edit(mission_id, edit_mission_entity)
+            .edit(mission_id, chief_id, edit_mission_entity, tags)
             .await?;
 
         Ok(result)
     }
 
-    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
+    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> AppResult<()> {
+        let mission =
+            authorize_chief(self.mission_viewing_repository.as_ref(), mission_id, chief_id).await?;
+        ensure_editable(&mission)?;
+
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
             .await?;
         if crew_count > 0 {
-            return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
+            return Err(AppError::Conflict(
+                "Mission has been taken by brawler for now!".to_string(),
             ));
         }
 
@@ -88,4 +163,58 @@ where
             .await?;
         Ok(())
     }
-}
\ No newline at end of file
+}
+fn validate_schedule(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>, timezone: &str) -> AppResult<()> {
+    if ends_at <= starts_at {
+        return Err(AppError::Validation(
+            "Mission must end after it starts".to_string(),
+        ));
+    }
+
+    if timezone.parse::<Tz>().is_err() {
+        return Err(AppError::Validation(format!(
+            "Unknown timezone: {}",
+            timezone
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_capacity(min_crew: i32, max_crew: i32) -> AppResult<()> {
+    if !(1..=MAX_CREW_LIMIT).contains(&max_crew) {
+        return Err(AppError::Validation(format!(
+            "max_crew must be between 1 and {}",
+            MAX_CREW_LIMIT
+        )));
+    }
+
+    if !(1..=max_crew).contains(&min_crew) {
+        return Err(AppError::Validation(
+            "min_crew must be between 1 and max_crew".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn ensure_editable(mission: &MissionEntity) -> AppResult<()> {
+    if !mission.status.is_editable() {
+        return Err(AppError::Conflict(format!(
+            "A {} mission can no longer be changed",
+            mission.status
+        )));
+    }
+    Ok(())
+}
+
+fn validate_tags(tags: &[String]) -> AppResult<Vec<String>> {
+    let tags = normalize_tags(tags)?;
+    if tags.len() > MAX_TAGS_PER_MISSION {
+        return Err(AppError::Validation(format!(
+            "A mission can have at most {} tags",
+            MAX_TAGS_PER_MISSION
+        )));
+    }
+    Ok(tags)
+}
@@ -2,17 +2,49 @@
 mod tests {
     use std::sync::Arc;
 
+    use chrono::{Duration, Utc};
+
     use crate::{
         application::use_cases::mission_management::MissionManagementUseCase,
         domain::{
+            app_error::AppError,
+            entities::missions::MissionEntity,
             repositories::{
                 mission_management::MockMissionManagementRepository,
                 mission_viewing::MockMissionViewingRepository,
             },
-            value_objects::mission_model::{NewMissionModel, UpdateMissionModel},
+            value_objects::{
+                join_policy::JoinPolicy,
+                mission_model::{NewMissionModel, UpdateMissionModel},
+                mission_statuses::MissionStatuses,
+                venue_model::VenueModel,
+            },
         },
     };
 
+    fn mission(chief_id: i32) -> MissionEntity {
+        let now = Utc::now().naive_utc();
+        MissionEntity {
+            id: 1,
+            name: "Test Mission".to_string(),
+            description: None,
+            status: MissionStatuses::Open,
+            chief_id,
+            created_at: now,
+            updated_at: now,
+            starts_at: now,
+            ends_at: now + Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue_name: None,
+            venue_address: None,
+            latitude: None,
+            longitude: None,
+            max_crew: 10,
+            min_crew: 1,
+            join_policy: JoinPolicy::Open.to_string(),
+        }
+    }
+
     #[tokio::test]
     async fn test_add() {
         let mut mock_mission_management_repository = MockMissionManagementRepository::new();
@@ -20,7 +52,7 @@ mod tests {
 
         mock_mission_management_repository
             .expect_add()
-            .returning(|_| Box::pin(async { Ok(1) }));
+            .returning(|_, _| Box::pin(async { Ok(1) }));
 
         let mission_management_use_case = MissionManagementUseCase::new(
             Arc::new(mock_mission_management_repository),
@@ -29,6 +61,15 @@ mod tests {
         let add_mission_model = NewMissionModel {
             name: "Test Mission".to_string(),
             description: Some("Test Description".to_string()),
+            starts_at: Utc::now() + Duration::days(1),
+            ends_at: Utc::now() + Duration::days(1) + Duration::hours(2),
+            timezone: "Asia/Bangkok".to_string(),
+            venue: None,
+            max_crew: None,
+            min_crew: None,
+            join_policy: None,
+            tags: vec![],
+            draft: false,
         };
 
         let result = mission_management_use_case
@@ -38,27 +79,163 @@ mod tests {
         assert_eq!(result, 1);
     }
 
+    #[tokio::test]
+    async fn test_add_normalizes_tags() {
+        let mut mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_management_repository
+            .expect_add()
+            .withf(|_, tags| tags == &vec!["board games".to_string(), "outdoor".to_string()])
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let add_mission_model = NewMissionModel {
+            name: "Test Mission".to_string(),
+            description: None,
+            starts_at: Utc::now() + Duration::days(1),
+            ends_at: Utc::now() + Duration::days(1) + Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue: None,
+            max_crew: None,
+            min_crew: None,
+            join_policy: None,
+            tags: vec![
+                " Board   Games ".to_string(),
+                "outdoor".to_string(),
+                "board games".to_string(),
+            ],
+            draft: false,
+        };
+
+        let result = mission_management_use_case.add(1, add_mission_model).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_draft() {
+        let mut mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_management_repository
+            .expect_add()
+            .withf(|mission, _| mission.status == MissionStatuses::Draft)
+            .returning(|_, _| Box::pin(async { Ok(1) }));
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let add_mission_model = NewMissionModel {
+            name: "Test Mission".to_string(),
+            description: None,
+            starts_at: Utc::now() + Duration::days(1),
+            ends_at: Utc::now() + Duration::days(1) + Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue: None,
+            max_crew: None,
+            min_crew: None,
+            join_policy: None,
+            tags: vec![],
+            draft: true,
+        };
+
+        let result = mission_management_use_case.add(1, add_mission_model).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_invalid_tags() {
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(MockMissionManagementRepository::new()),
+            Arc::new(MockMissionViewingRepository::new()),
+        );
+        let new_mission = |tags: Vec<String>| NewMissionModel {
+            name: "Test Mission".to_string(),
+            description: None,
+            starts_at: Utc::now() + Duration::days(1),
+            ends_at: Utc::now() + Duration::days(1) + Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue: None,
+            max_crew: None,
+            min_crew: None,
+            join_policy: None,
+            tags,
+            draft: false,
+        };
+
+        let result = mission_management_use_case
+            .add(1, new_mission(vec!["no_underscores".to_string()]))
+            .await;
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        let too_many = (0..11).map(|i| format!("tag {}", i)).collect();
+        let result = mission_management_use_case
+            .add(1, new_mission(too_many))
+            .await;
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_edit_replaces_tags() {
+        let mut mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        mock_mission_management_repository
+            .expect_edit()
+            .withf(|_, _, _, tags| tags == &Some(vec!["chess".to_string()]))
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let edit_model = UpdateMissionModel {
+            tags: Some(vec!["Chess".to_string()]),
+            ..Default::default()
+        };
+
+        let result = mission_management_use_case.edit(1, 1, edit_model).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
     #[tokio::test]
     async fn test_edit_success() {
         let mut mock_mission_management_repository = MockMissionManagementRepository::new();
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
         mock_mission_viewing_repository
             .expect_crew_counting()
             .returning(|_| Box::pin(async { Ok(0) }));
 
         mock_mission_management_repository
             .expect_edit()
-            .returning(|_, _| Box::pin(async { Ok(1) }));
+            .withf(|mission_id, chief_id, _, tags| *mission_id == 1 && *chief_id == 1 && tags.is_none())
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
 
         let mission_management_use_case = MissionManagementUseCase::new(
             Arc::new(mock_mission_management_repository),
             Arc::new(mock_mission_viewing_repository),
         );
         let edit_model = UpdateMissionModel {
-            name: None,
-            status: None,
             description: Some("Test".to_string()),
+            ..Default::default()
         };
 
         let result = mission_management_use_case
@@ -68,11 +245,42 @@ mod tests {
         assert_eq!(result, 1);
     }
 
+    #[tokio::test]
+    async fn test_edit_started_mission() {
+        let mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository.expect_view_detail().returning(|_| {
+            Box::pin(async {
+                Ok(MissionEntity {
+                    status: MissionStatuses::InProgress,
+                    ..mission(1)
+                })
+            })
+        });
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let edit_model = UpdateMissionModel {
+            description: Some("Test".to_string()),
+            ..Default::default()
+        };
+
+        let result = mission_management_use_case.edit(1, 1, edit_model).await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
     #[tokio::test]
     async fn test_edit_failed() {
         let mock_mission_management_repository = MockMissionManagementRepository::new();
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
         mock_mission_viewing_repository
             .expect_crew_counting()
             .returning(|_| Box::pin(async { Ok(13) }));
@@ -82,9 +290,8 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
         let edit_model = UpdateMissionModel {
-            name: None,
-            status: None,
             description: Some("Test".to_string()),
+            ..Default::default()
         };
 
         let result = mission_management_use_case.edit(1, 1, edit_model).await;
@@ -92,26 +299,76 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_edit_by_non_chief_is_forbidden() {
+        let mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let edit_model = UpdateMissionModel {
+            name: Some("Taken over".to_string()),
+            ..Default::default()
+        };
+
+        let result = mission_management_use_case.edit(1, 2, edit_model).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_edit_missing_mission_is_not_found() {
+        let mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository.expect_view_detail().returning(|_| {
+            Box::pin(async { Err(anyhow::Error::from(diesel::result::Error::NotFound)) })
+        });
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let edit_model = UpdateMissionModel {
+            description: Some("Test".to_string()),
+            ..Default::default()
+        };
+
+        let result = mission_management_use_case.edit(99, 1, edit_model).await;
+
+        assert!(matches!(result, Err(AppError::NotFound(_))));
+    }
+
     #[tokio::test]
     async fn test_remove_success() {
         let mut mock_mission_management_repository = MockMissionManagementRepository::new();
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
         mock_mission_viewing_repository
             .expect_crew_counting()
             .returning(|_| Box::pin(async { Ok(0) }));
 
         mock_mission_management_repository
             .expect_remove()
+            .withf(|mission_id, chief_id| *mission_id == 1 && *chief_id == 1)
             .returning(|_, _| Box::pin(async { Ok(()) }));
         let mission_management_use_case = MissionManagementUseCase::new(
             Arc::new(mock_mission_management_repository),
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = mission_management_use_case.remove(1, 1).await.unwrap();
+        let result = mission_management_use_case.remove(1, 1).await;
 
-        assert_eq!(result, ());
+        assert!(result.is_ok());
     }
 
     #[tokio::test]
@@ -119,6 +376,9 @@ mod tests {
     let mock_mission_management_repository = MockMissionManagementRepository::new();
     let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+    mock_mission_viewing_repository
+        .expect_view_detail()
+        .returning(|_| Box::pin(async { Ok(mission(1)) }));
     mock_mission_viewing_repository
         .expect_crew_counting()
         .returning(|_| Box::pin(async { Ok(9) }));
@@ -132,4 +392,145 @@ mod tests {
     assert!(result.is_err());
 }
 
-}
\ No newline at end of file
+    #[tokio::test]
+    async fn test_remove_by_non_chief_is_forbidden() {
+        let mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = mission_management_use_case.remove(1, 2).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    fn new_mission_model(
+        starts_at: chrono::DateTime<Utc>,
+        ends_at: chrono::DateTime<Utc>,
+        timezone: &str,
+    ) -> NewMissionModel {
+        NewMissionModel {
+            name: "Test Mission".to_string(),
+            description: None,
+            starts_at,
+            ends_at,
+            timezone: timezone.to_string(),
+            venue: None,
+            max_crew: None,
+            min_crew: None,
+            join_policy: None,
+            tags: vec![],
+            draft: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_invalid_schedule() {
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(MockMissionManagementRepository::new()),
+            Arc::new(MockMissionViewingRepository::new()),
+        );
+        let tomorrow = Utc::now() + Duration::days(1);
+
+        let ends_before_start = mission_management_use_case
+            .add(1, new_mission_model(tomorrow, tomorrow - Duration::hours(1), "UTC"))
+            .await;
+        let in_the_past = mission_management_use_case
+            .add(1, new_mission_model(Utc::now() - Duration::days(1), tomorrow, "UTC"))
+            .await;
+        let unknown_timezone = mission_management_use_case
+            .add(1, new_mission_model(tomorrow, tomorrow + Duration::hours(1), "Mars/Olympus"))
+            .await;
+
+        assert!(matches!(ends_before_start, Err(AppError::Validation(_))));
+        assert!(matches!(in_the_past, Err(AppError::Validation(_))));
+        assert!(matches!(unknown_timezone, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_edit_rejects_end_before_current_start() {
+        let mock_mission_management_repository = MockMissionManagementRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission(1)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(0) }));
+
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(mock_mission_management_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let edit_model = UpdateMissionModel {
+            ends_at: Some(Utc::now() - Duration::days(1)),
+            ..Default::default()
+        };
+
+        let result = mission_management_use_case.edit(1, 1, edit_model).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_invalid_venue() {
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(MockMissionManagementRepository::new()),
+            Arc::new(MockMissionViewingRepository::new()),
+        );
+        let tomorrow = Utc::now() + Duration::days(1);
+        let add_mission_model = NewMissionModel {
+            venue: Some(VenueModel {
+                name: "Nowhere".to_string(),
+                address: None,
+                latitude: 91.0,
+                longitude: 100.0,
+            }),
+            ..new_mission_model(tomorrow, tomorrow + Duration::hours(1), "UTC")
+        };
+
+        let result = mission_management_use_case.add(1, add_mission_model).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_rejects_invalid_capacity() {
+        let mission_management_use_case = MissionManagementUseCase::new(
+            Arc::new(MockMissionManagementRepository::new()),
+            Arc::new(MockMissionViewingRepository::new()),
+        );
+        let tomorrow = Utc::now() + Duration::days(1);
+
+        let too_large = mission_management_use_case
+            .add(
+                1,
+                NewMissionModel {
+                    max_crew: Some(1000),
+                    ..new_mission_model(tomorrow, tomorrow + Duration::hours(1), "UTC")
+                },
+            )
+            .await;
+        let min_above_max = mission_management_use_case
+            .add(
+                1,
+                NewMissionModel {
+                    max_crew: Some(4),
+                    min_crew: Some(6),
+                    ..new_mission_model(tomorrow, tomorrow + Duration::hours(1), "UTC")
+                },
+            )
+            .await;
+
+        assert!(matches!(too_large, Err(AppError::Validation(_))));
+        assert!(matches!(min_above_max, Err(AppError::Validation(_))));
+    }
+}
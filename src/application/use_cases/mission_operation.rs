@@ -1,12 +1,19 @@
-use anyhow::Result;
 use std::sync::Arc;
 
-use crate::domain::{
-    entities::crew_memberships::MAX_CREW_MEMBERSHIPS_PER_MISSION,
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
+use crate::{
+    application::use_cases::mission_authorization::{authorize_chief, authorize_leader},
+    domain::{
+        app_error::{AppError, AppResult},
+        entities::missions::MissionEntity,
+        repositories::{
+            mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository,
+        },
+        value_objects::{
+            crew_model::CrewRoles, mission_status_event_model::MissionStatusChangeModel,
+            mission_statuses::MissionStatuses,
+        },
     },
-    value_objects::mission_statuses::MissionStatuses,
 };
 
 pub struct MissionOperationUseCase<T1, T2>
@@ -30,71 +37,135 @@ where
         }
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self
-            .mission_viewing_repository
-            .view_detail(mission_id)
-            .await?;
+    pub async fn in_progress(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        status_change: &MissionStatusChangeModel,
+    ) -> AppResult<i32> {
+        let reason = status_change.reason()?;
+        let mission = authorize_leader(
+            self.mission_viewing_repository.as_ref(),
+            mission_id,
+            brawler_id,
+        )
+        .await?;
 
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
+        self.change_status(&mission, MissionStatuses::InProgress, brawler_id, reason)
+            .await
+    }
 
-        let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        let update_condition = is_status_open_or_fail
-            && crew_count > 0
-            && crew_count < MAX_CREW_MEMBERSHIPS_PER_MISSION
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+    pub async fn to_completed(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        status_change: &MissionStatusChangeModel,
+    ) -> AppResult<i32> {
+        let reason = status_change.reason()?;
+        let mission = authorize_leader(
+            self.mission_viewing_repository.as_ref(),
+            mission_id,
+            brawler_id,
+        )
+        .await?;
 
-        let result = self
-            .mission_operation_repository
-            .in_progress(mission_id, chief_id)
-            .await?;
-        Ok(result)
+        self.change_status(&mission, MissionStatuses::Completed, brawler_id, reason)
+            .await
     }
 
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self
-            .mission_viewing_repository
-            .view_detail(mission_id)
-            .await?;
+    pub async fn to_failed(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        status_change: &MissionStatusChangeModel,
+    ) -> AppResult<i32> {
+        let reason = status_change.reason()?;
+        let mission = authorize_leader(
+            self.mission_viewing_repository.as_ref(),
+            mission_id,
+            brawler_id,
+        )
+        .await?;
 
-        let update_condition =
-            mission.status == MissionStatuses::InProgress.to_string() && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+        self.change_status(&mission, MissionStatuses::Failed, brawler_id, reason)
+            .await
+    }
 
-        let result = self
-            .mission_operation_repository
-            .to_completed(mission_id, chief_id)
+    /// Opens a draft mission to browsing and crew.
+    pub async fn publish(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        status_change: &MissionStatusChangeModel,
+    ) -> AppResult<i32> {
+        let reason = status_change.reason()?;
+        let mission =
+            authorize_chief(self.mission_viewing_repository.as_ref(), mission_id, chief_id).await?;
+
+        self.change_status(&mission, MissionStatuses::Open, chief_id, reason)
+            .await
+    }
+
+    pub async fn cancel(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        status_change: &MissionStatusChangeModel,
+    ) -> AppResult<i32> {
+        let reason = status_change.reason()?;
+        let mission =
+            authorize_chief(self.mission_viewing_repository.as_ref(), mission_id, chief_id).await?;
+
+        self.change_status(&mission, MissionStatuses::Cancelled, chief_id, reason)
+            .await
+    }
+
+    pub async fn set_crew_role(
+        &self,
+        mission_id: i32,
+        brawler_id: i32,
+        chief_id: i32,
+        role: CrewRoles,
+    ) -> AppResult<()> {
+        authorize_chief(self.mission_viewing_repository.as_ref(), mission_id, chief_id).await?;
+        self.find_crew_member(mission_id, brawler_id).await?;
+
+        self.mission_operation_repository
+            .set_crew_role(mission_id, brawler_id, role.to_string())
             .await?;
 
-        Ok(result)
+        Ok(())
     }
 
-    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self
+    async fn change_status(
+        &self,
+        mission: &MissionEntity,
+        to_status: MissionStatuses,
+        actor_id: i32,
+        reason: Option<String>,
+    ) -> AppResult<i32> {
+        // Fails fast on the common case; the repository repeats the check under the lock.
+        let crew_count = self
             .mission_viewing_repository
-            .view_detail(mission_id)
+            .crew_counting(mission.id)
             .await?;
-
-        let update_condition =
-            mission.status == MissionStatuses::InProgress.to_string() && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+        mission
+            .status
+            .transition_to(to_status, mission.transition_guards(crew_count))?;
 
         let result = self
             .mission_operation_repository
-            .to_failed(mission_id, chief_id)
+            .change_status(mission.id, to_status, actor_id, reason)
             .await?;
 
         Ok(result)
     }
-}
\ No newline at end of file
+
+    async fn find_crew_member(&self, mission_id: i32, brawler_id: i32) -> AppResult<String> {
+        self.mission_viewing_repository
+            .crew_role(mission_id, brawler_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Brawler is not in the crew".to_string()))
+    }
+}
+
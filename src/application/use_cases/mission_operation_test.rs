@@ -5,14 +5,17 @@ mod tests {
     use crate::{
         application::use_cases::mission_operation::MissionOperationUseCase,
         domain::{
-            entities::{
-                crew_memberships::MAX_CREW_MEMBERSHIPS_PER_MISSION, missions::MissionEntity,
-            },
+            app_error::AppError,
+            entities::missions::MissionEntity,
             repositories::{
                 mission_operation::MockMissionOperationRepository,
                 mission_viewing::MockMissionViewingRepository,
             },
-            value_objects::mission_statuses::MissionStatuses,
+            value_objects::{
+                crew_model::CrewRoles, join_policy::JoinPolicy,
+                mission_status_event_model::MissionStatusChangeModel,
+                mission_statuses::{MissionStatuses, TransitionGuards},
+            },
         },
     };
 
@@ -26,8 +29,9 @@ mod tests {
             .returning(|_| Box::pin(async { Ok(2) }));
 
         mock_mission_operation_repository
-            .expect_in_progress()
-            .returning(|_, _| Box::pin(async { Ok(1) }));
+            .expect_change_status()
+            .withf(|_, to_status, _, _| *to_status == MissionStatuses::InProgress)
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
         mock_mission_viewing_repository
             .expect_view_detail()
             .returning(|_| {
@@ -37,10 +41,20 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::Open.to_string(),
+                        status: MissionStatuses::Open,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
@@ -49,7 +63,7 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.in_progress(1, 1).await;
+        let result = use_case.in_progress(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_ok())
     }
@@ -61,7 +75,52 @@ mod tests {
 
         mock_mission_viewing_repository
             .expect_crew_counting()
-            .returning(|_| Box::pin(async { Ok(MAX_CREW_MEMBERSHIPS_PER_MISSION + 1) }));
+            .returning(|_| Box::pin(async { Ok(11) }));
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| {
+                let now = chrono::Utc::now().naive_utc();
+                Box::pin(async move {
+                    Ok(MissionEntity {
+                        id: 1,
+                        name: "Test Mission".to_string(),
+                        description: None,
+                        status: MissionStatuses::Open,
+                        chief_id: 1,
+                        created_at: now,
+                        updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
+                    })
+                })
+            });
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.in_progress(1, 1, &MissionStatusChangeModel::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_in_progress_below_min_crew() {
+        let mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
 
         mock_mission_viewing_repository
             .expect_view_detail()
@@ -72,10 +131,20 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::Open.to_string(),
+                        status: MissionStatuses::Open,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 3,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
@@ -84,7 +153,7 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.in_progress(1, 1).await;
+        let result = use_case.in_progress(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
@@ -106,10 +175,20 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::Completed.to_string(),
+                        status: MissionStatuses::Completed,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
@@ -118,7 +197,7 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.in_progress(1, 1).await;
+        let result = use_case.in_progress(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
@@ -128,6 +207,10 @@ mod tests {
         let mock_mission_operation_repository = MockMissionOperationRepository::new();
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
         mock_mission_viewing_repository
             .expect_crew_counting()
             .returning(|_| Box::pin(async { Ok(1) }));
@@ -140,10 +223,20 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::Open.to_string(),
+                        status: MissionStatuses::Open,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
@@ -152,7 +245,7 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.in_progress(1, 10).await;
+        let result = use_case.in_progress(1, 10, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
@@ -163,8 +256,9 @@ mod tests {
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
         mock_mission_operation_repository
-            .expect_to_completed()
-            .returning(|_, _| Box::pin(async { Ok(1) }));
+            .expect_change_status()
+            .withf(|_, to_status, _, _| *to_status == MissionStatuses::Completed)
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
         mock_mission_viewing_repository
             .expect_view_detail()
             .returning(|_| {
@@ -174,19 +268,32 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::InProgress.to_string(),
+                        status: MissionStatuses::InProgress,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
         let use_case = MissionOperationUseCase::new(
             Arc::new(mock_mission_operation_repository),
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.to_completed(1, 1).await;
+        let result = use_case.to_completed(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_ok());
     }
@@ -205,19 +312,32 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::Open.to_string(),
+                        status: MissionStatuses::Open,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
         let use_case = MissionOperationUseCase::new(
             Arc::new(mock_mission_operation_repository),
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.to_completed(1, 1).await;
+        let result = use_case.to_completed(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
@@ -226,6 +346,10 @@ mod tests {
         let mock_mission_operation_repository = MockMissionOperationRepository::new();
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
         mock_mission_viewing_repository
             .expect_view_detail()
             .returning(|_| {
@@ -235,10 +359,20 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::InProgress.to_string(),
+                        status: MissionStatuses::InProgress,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
@@ -247,7 +381,7 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.to_completed(1, 10).await;
+        let result = use_case.to_completed(1, 10, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
@@ -257,8 +391,9 @@ mod tests {
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
         mock_mission_operation_repository
-            .expect_to_failed()
-            .returning(|_, _| Box::pin(async { Ok(1) }));
+            .expect_change_status()
+            .withf(|_, to_status, _, _| *to_status == MissionStatuses::Failed)
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
         mock_mission_viewing_repository
             .expect_view_detail()
             .returning(|_| {
@@ -268,19 +403,32 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::InProgress.to_string(),
+                        status: MissionStatuses::InProgress,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
         let use_case = MissionOperationUseCase::new(
             Arc::new(mock_mission_operation_repository),
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.to_failed(1, 1).await;
+        let result = use_case.to_failed(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_ok());
     }
@@ -298,19 +446,32 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::Completed.to_string(),
+                        status: MissionStatuses::Completed,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
         let use_case = MissionOperationUseCase::new(
             Arc::new(mock_mission_operation_repository),
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.to_failed(1, 1).await;
+        let result = use_case.to_failed(1, 1, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
@@ -319,6 +480,10 @@ mod tests {
         let mock_mission_operation_repository = MockMissionOperationRepository::new();
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
         mock_mission_viewing_repository
             .expect_view_detail()
             .returning(|_| {
@@ -328,10 +493,20 @@ mod tests {
                         id: 1,
                         name: "Test Mission".to_string(),
                         description: None,
-                        status: MissionStatuses::InProgress.to_string(),
+                        status: MissionStatuses::InProgress,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
@@ -340,8 +515,331 @@ mod tests {
             Arc::new(mock_mission_viewing_repository),
         );
 
-        let result = use_case.to_failed(1, 10).await;
+        let result = use_case.to_failed(1, 10, &MissionStatusChangeModel::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    fn mission_with_status(status: MissionStatuses) -> MissionEntity {
+        let now = chrono::Utc::now().naive_utc();
+        MissionEntity {
+            id: 1,
+            name: "Test Mission".to_string(),
+            description: None,
+            status,
+            chief_id: 1,
+            created_at: now,
+            updated_at: now,
+            starts_at: now,
+            ends_at: now + chrono::Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue_name: None,
+            venue_address: None,
+            latitude: None,
+            longitude: None,
+            max_crew: 10,
+            min_crew: 1,
+            join_policy: JoinPolicy::Open.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_by_co_chief() {
+        let mut mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| {
+                Box::pin(async { Ok(mission_with_status(MissionStatuses::InProgress)) })
+            });
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(Some(CrewRoles::CoChief.to_string())) }));
+        mock_mission_operation_repository
+            .expect_change_status()
+            .withf(|_, to_status, _, _| *to_status == MissionStatuses::Completed)
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
+
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.to_completed(1, 10, &MissionStatusChangeModel::default()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_completed_by_plain_member() {
+        let mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| {
+                Box::pin(async { Ok(mission_with_status(MissionStatuses::InProgress)) })
+            });
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(Some(CrewRoles::Member.to_string())) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.to_completed(1, 10, &MissionStatusChangeModel::default()).await;
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_set_crew_role() {
+        let mut mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Open)) }));
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(Some(CrewRoles::Member.to_string())) }));
+        mock_mission_operation_repository
+            .expect_set_crew_role()
+            .withf(|_, brawler_id, role| *brawler_id == 10 && role == "CoChief")
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.set_crew_role(1, 10, 1, CrewRoles::CoChief).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_failed_records_actor_and_reason() {
+        let mut mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::InProgress)) }));
+        mock_mission_operation_repository
+            .expect_change_status()
+            .withf(|mission_id, to_status, actor_id, reason| {
+                *mission_id == 1
+                    && *to_status == MissionStatuses::Failed
+                    && *actor_id == 1
+                    && reason.as_deref() == Some("Venue closed")
+            })
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
+
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let status_change = MissionStatusChangeModel {
+            reason: Some("  Venue closed ".to_string()),
+        };
+
+        let result = use_case.to_failed(1, 1, &status_change).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_to_failed_rejects_oversized_reason() {
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(MockMissionOperationRepository::new()),
+            Arc::new(MockMissionViewingRepository::new()),
+        );
+        let status_change = MissionStatusChangeModel {
+            reason: Some("x".repeat(501)),
+        };
+
+        let result = use_case.to_failed(1, 1, &status_change).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_publish_draft() {
+        let mut mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Draft)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(0) }));
+        mock_mission_operation_repository
+            .expect_change_status()
+            .withf(|_, to_status, actor_id, _| *to_status == MissionStatuses::Open && *actor_id == 1)
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.publish(1, 1, &MissionStatusChangeModel::default()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_in_progress_from_draft() {
+        let mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Draft)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.in_progress(1, 1, &MissionStatusChangeModel::default()).await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_in_progress_lost_race_is_conflict() {
+        let mut mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Open)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
+        // Someone else moved the mission after the first check but before the lock.
+        mock_mission_operation_repository
+            .expect_change_status()
+            .returning(|_, _, _, _| {
+                Box::pin(async {
+                    Err(anyhow::Error::from(AppError::Conflict(
+                        "A mission cannot go from Cancelled to InProgress".to_string(),
+                    )))
+                })
+            });
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.in_progress(1, 1, &MissionStatusChangeModel::default()).await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel() {
+        let mut mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Failed)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
+        mock_mission_operation_repository
+            .expect_change_status()
+            .withf(|_, to_status, _, reason| {
+                *to_status == MissionStatuses::Cancelled && reason.as_deref() == Some("Rained out")
+            })
+            .returning(|_, _, _, _| Box::pin(async { Ok(1) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+        let status_change = MissionStatusChangeModel {
+            reason: Some("Rained out".to_string()),
+        };
+
+        let result = use_case.cancel(1, 1, &status_change).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_completed() {
+        let mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Completed)) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(2) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.cancel(1, 1, &MissionStatusChangeModel::default()).await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_by_co_chief() {
+        let mock_mission_operation_repository = MockMissionOperationRepository::new();
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|_| Box::pin(async { Ok(mission_with_status(MissionStatuses::Open)) }));
+
+        let use_case = MissionOperationUseCase::new(
+            Arc::new(mock_mission_operation_repository),
+            Arc::new(mock_mission_viewing_repository),
+        );
+
+        let result = use_case.cancel(1, 10, &MissionStatusChangeModel::default()).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[test]
+    fn test_mission_status_transitions() {
+        let enough_crew = TransitionGuards { has_enough_crew: true };
+
+        assert!(MissionStatuses::Open.transition_to(MissionStatuses::InProgress, enough_crew).is_ok());
+        assert!(MissionStatuses::Failed.transition_to(MissionStatuses::InProgress, enough_crew).is_ok());
+        assert!(
+            MissionStatuses::Open
+                .transition_to(MissionStatuses::InProgress, TransitionGuards::default())
+                .is_err()
+        );
+        assert!(MissionStatuses::Open.transition_to(MissionStatuses::Completed, enough_crew).is_err());
+        assert!(MissionStatuses::Cancelled.allowed_transitions().is_empty());
+        assert!(MissionStatuses::Completed.allowed_transitions().is_empty());
+        assert!(!MissionStatuses::Draft.accepts_crew_changes());
+        assert!(!MissionStatuses::InProgress.is_editable());
+    }
 }
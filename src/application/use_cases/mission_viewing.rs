@@ -1,7 +1,6 @@
 use std::sync::Arc;
-use anyhow::Result;
 
-use crate::domain::{repositories::mission_viewing::MissionViewingRepository, value_objects::{mission_filter::MissionFilter, mission_model::MissionModel}};
+use crate::{application::use_cases::mission_authorization::find_visible_mission, domain::{app_error::{AppError, AppResult}, repositories::mission_viewing::MissionViewingRepository, value_objects::{crew_model::CrewMemberModel, mission_filter::MissionFilter, mission_model::MissionModel, mission_status_event_model::MissionStatusEventModel, mission_viewer::MissionViewer, pagination::{PageQuery, Paginated}, tag_model::{PopularTagsQuery, TagCountModel, group_mission_tags}, venue_model::haversine_km}}};
 
 pub struct MissionViewingUseCase<T>
 where
@@ -21,38 +20,128 @@ where
     }
 
 
-    pub async fn view_detail(&self, mission_id: i32) -> Result<MissionModel> {
+    pub async fn view_detail(&self, mission_id: i32, viewer: &MissionViewer) -> AppResult<MissionModel> {
+        let model = find_visible_mission(self.mission_viewing_repository.as_ref(), mission_id, viewer).await?;
+
         let crew_count = self
             .mission_viewing_repository
             .crew_counting(mission_id)
             .await?;
 
-        let model: crate::domain::entities::missions::MissionEntity = self
+        let chief = self
             .mission_viewing_repository
-            .view_detail(mission_id)
+            .chief_profile(model.chief_id)
             .await?;
 
-        let result = model.to_model(crew_count.into());
+        let tags = self
+            .mission_viewing_repository
+            .mission_tags(&[mission_id])
+            .await?;
+
+        let mut result = model.to_model(&chief, crew_count.into());
+        result.tags = tags.into_iter().map(|(_, tag)| tag).collect();
 
         Ok(result)
     }
 
-    pub async fn get(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let models: Vec<crate::domain::entities::missions::MissionEntity> = self.mission_viewing_repository.get(filter).await?;
+    pub async fn get(&self, filter: &MissionFilter) -> AppResult<Paginated<MissionModel>> {
+        if let (Some(starts_from), Some(starts_to)) = (filter.starts_from, filter.starts_to)
+            && starts_from > starts_to
+        {
+            return Err(AppError::Validation(
+                "starts_from must not be after starts_to".to_string(),
+            ));
+        }
+
+        let near = filter.near()?;
+        filter.sort_by()?;
+        filter.tag_filters()?;
+        let (page, limit) = filter.page_query().resolve()?;
+
+        let total = self.mission_viewing_repository.count(filter).await?;
+        let models = self
+            .mission_viewing_repository
+            .get(filter, limit, (page - 1) * limit)
+            .await?;
+
+        let mission_ids: Vec<i32> = models.iter().map(|(model, ..)| model.id).collect();
+        let mut tags = group_mission_tags(
+            self.mission_viewing_repository
+                .mission_tags(&mission_ids)
+                .await?,
+        );
 
         let mut result = Vec::new();
 
-        for model in models.into_iter() {
-            let crew_count = self
-                .mission_viewing_repository
-                .crew_counting(model.id)
-                .await
-                .unwrap_or(0);
+        for (model, chief, crew_count, snippet) in models.into_iter() {
+            let mut mission_model = model.to_model(&chief, crew_count);
+            mission_model.snippet = snippet;
+            mission_model.tags = tags.remove(&mission_model.id).unwrap_or_default();
+            if let (Some((center, _)), Some(venue)) = (near, &mission_model.venue) {
+                mission_model.distance_km = Some(haversine_km(center, (venue.latitude, venue.longitude)));
+            }
 
-            result.push(model.to_model(crew_count.into()));
+            result.push(mission_model);
         }
 
-        Ok(result)
+        Ok(Paginated::new(result, page, limit, total))
+    }
+
+    pub async fn crew_roster(
+        &self,
+        mission_id: i32,
+        page_query: &PageQuery,
+        viewer: &MissionViewer,
+    ) -> AppResult<Paginated<CrewMemberModel>> {
+        let (page, limit) = page_query.resolve()?;
+        find_visible_mission(self.mission_viewing_repository.as_ref(), mission_id, viewer).await?;
+
+        let total = self
+            .mission_viewing_repository
+            .crew_counting(mission_id)
+            .await?;
+
+        let members = self
+            .mission_viewing_repository
+            .crew_roster(mission_id, limit, (page - 1) * limit)
+            .await?;
+
+        let items = members
+            .iter()
+            .map(|(membership, brawler)| membership.to_model(brawler))
+            .collect();
+
+        Ok(Paginated::new(items, page, limit, total.into()))
     }
 
-}
\ No newline at end of file
+    pub async fn status_history(&self, mission_id: i32, viewer: &MissionViewer) -> AppResult<Vec<MissionStatusEventModel>> {
+        find_visible_mission(self.mission_viewing_repository.as_ref(), mission_id, viewer).await?;
+
+        let events = self
+            .mission_viewing_repository
+            .status_history(mission_id)
+            .await?;
+
+        Ok(events
+            .iter()
+            .map(|(event, actor)| event.to_model(actor))
+            .collect())
+    }
+
+    pub async fn popular_tags(&self, query: &PopularTagsQuery) -> AppResult<Vec<TagCountModel>> {
+        let limit = query.limit()?;
+
+        let tags = self
+            .mission_viewing_repository
+            .popular_tags(limit)
+            .await?;
+
+        Ok(tags
+            .into_iter()
+            .map(|(name, mission_count)| TagCountModel {
+                name,
+                mission_count,
+            })
+            .collect())
+    }
+}
@@ -5,12 +5,45 @@ mod tests {
     use crate::{
         application::use_cases::mission_viewing::MissionViewingUseCase,
         domain::{
-            entities::missions::MissionEntity,
+            app_error::AppError,
+            entities::{brawlers::BrawlerProfileEntity, crew_memberships::CrewMembershipEntity, mission_status_events::MissionStatusEventEntity, missions::MissionEntity},
             repositories::mission_viewing::MockMissionViewingRepository,
-            value_objects::{mission_filter::MissionFilter, mission_model::MissionModel, mission_statuses::MissionStatuses},
+            value_objects::{crew_model::CrewRoles, join_policy::JoinPolicy, mission_filter::{MissionFilter, MissionPeriod, MissionSort}, mission_model::MissionModel, mission_statuses::MissionStatuses, mission_viewer::MissionViewer, pagination::{PageQuery, Paginated}, tag_model::{PopularTagsQuery, TagCountModel}},
         },
+        infrastructure::sha256::sha256_hex,
     };
 
+    fn chief() -> BrawlerProfileEntity {
+        BrawlerProfileEntity {
+            username: "chief".to_string(),
+            display_name: "Chief".to_string(),
+            avatar_url: Some("https://example.com/chief.png".to_string()),
+        }
+    }
+
+    fn invite_only_mission(mission_id: i32) -> MissionEntity {
+        let now = chrono::Utc::now().naive_utc();
+        MissionEntity {
+            id: mission_id,
+            name: "Secret".to_string(),
+            description: None,
+            status: MissionStatuses::Open,
+            chief_id: 1,
+            created_at: now,
+            updated_at: now,
+            starts_at: now,
+            ends_at: now + chrono::Duration::hours(2),
+            timezone: "UTC".to_string(),
+            venue_name: None,
+            venue_address: None,
+            latitude: None,
+            longitude: None,
+            max_crew: 10,
+            min_crew: 1,
+            join_policy: JoinPolicy::InviteOnly.to_string(),
+        }
+    }
+
     #[tokio::test]
     async fn test_view_details() {
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
@@ -29,13 +62,31 @@ mod tests {
                         id: mission_id,
                         name: "Test".to_string(),
                         description: None,
-                        status: MissionStatuses::Open.to_string(),
+                        status: MissionStatuses::Open,
                         chief_id: 1,
                         created_at: now,
                         updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
                     })
                 })
             });
+        mock_mission_viewing_repository
+            .expect_chief_profile()
+            .withf(|chief_id| *chief_id == 1)
+            .returning(|_| Box::pin(async { Ok(chief()) }));
+        mock_mission_viewing_repository
+            .expect_mission_tags()
+            .withf(|mission_ids| mission_ids == [98])
+            .returning(|_| Box::pin(async { Ok(vec![(98, "chess".to_string())]) }));
         let want = MissionModel {
             id: 98,
             name: "Test".to_string(),
@@ -43,12 +94,25 @@ mod tests {
             created_at: now,
             updated_at: now,
             chief_id: 1,
+            chief_display_name: "Chief".to_string(),
+            chief_avatar_url: Some("https://example.com/chief.png".to_string()),
             crew_count: 2,
-            status: MissionStatuses::Open.to_string(),
+            starts_at: now.and_utc(),
+            ends_at: (now + chrono::Duration::hours(2)).and_utc(),
+            timezone: "UTC".to_string(),
+            duration_minutes: 120,
+            venue: None,
+            max_crew: 10,
+            min_crew: 1,
+            join_policy: JoinPolicy::Open.to_string(),
+            distance_km: None,
+            snippet: None,
+            tags: vec!["chess".to_string()],
+            status: MissionStatuses::Open,
         };
 
         let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
-        let result = use_case.view_detail(98).await.unwrap();
+        let result = use_case.view_detail(98, &MissionViewer::default()).await.unwrap();
         assert_eq!(result, want)
     }
 
@@ -57,26 +121,657 @@ mod tests {
         let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
 
         mock_mission_viewing_repository
-            .expect_crew_counting()
-            .returning(|_| Box::pin(async { Ok(2) }));
-        mock_mission_viewing_repository.expect_get().returning(|_| {
+            .expect_count()
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_mission_viewing_repository.expect_get().returning(|_, _, _| {
             Box::pin(async {
                 let now = chrono::Utc::now().naive_utc();
-                Ok(vec![MissionEntity {
-                    id: 1,
-                    name: "Test 1".to_string(),
-                    description: None,
-                    status: MissionStatuses::Open.to_string(),
-                    chief_id: 1,
-                    created_at: now,
-                    updated_at: now,
-                }])
+                Ok(vec![(
+                    MissionEntity {
+                        id: 1,
+                        name: "Test 1".to_string(),
+                        description: None,
+                        status: MissionStatuses::Open,
+                        chief_id: 1,
+                        created_at: now,
+                        updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
+                    },
+                    chief(),
+                    2,
+                    None,
+                )])
             })
         });
+        mock_mission_viewing_repository
+            .expect_mission_tags()
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(vec![(1, "board games".to_string()), (1, "outdoor".to_string())])
+                })
+            });
         let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
 
         let result = use_case.get(&MissionFilter::default()).await.unwrap();
 
-        assert_eq!(result.len(), 1)
+        assert_eq!(result.items[0].tags, vec!["board games", "outdoor"]);
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].chief_display_name, "Chief");
+        assert_eq!(result.items[0].crew_count, 2);
+        assert_eq!(result.total, 1);
+        assert_eq!(result.next_page, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_rejects_inverted_date_range() {
+        let use_case = MissionViewingUseCase::new(Arc::new(MockMissionViewingRepository::new()));
+        let now = chrono::Utc::now();
+        let filter = MissionFilter {
+            starts_from: Some(now),
+            starts_to: Some(now - chrono::Duration::days(1)),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_this_week_ends_next_monday() {
+        // A Wednesday.
+        let now = chrono::DateTime::parse_from_rfc3339("2026-02-04T15:30:00Z")
+            .unwrap()
+            .to_utc();
+        let filter = MissionFilter {
+            period: Some(MissionPeriod::ThisWeek),
+            ..Default::default()
+        };
+
+        let (from, to) = filter.starts_between(now);
+
+        assert_eq!(from, Some(now));
+        assert_eq!(to.unwrap().to_rfc3339(), "2026-02-09T00:00:00+00:00");
+    }
+
+    #[tokio::test]
+    async fn test_get_near_reports_distance() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_count()
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_mission_viewing_repository.expect_get().returning(|_, _, _| {
+            Box::pin(async {
+                let now = chrono::Utc::now().naive_utc();
+                Ok(vec![(
+                    MissionEntity {
+                        id: 1,
+                        name: "Board games".to_string(),
+                        description: None,
+                        status: MissionStatuses::Open,
+                        chief_id: 1,
+                        created_at: now,
+                        updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "Asia/Bangkok".to_string(),
+                        venue_name: Some("Siam Paragon".to_string()),
+                        venue_address: None,
+                        latitude: Some(13.7462),
+                        longitude: Some(100.5347),
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
+                    },
+                    chief(),
+                    2,
+                    None,
+                )])
+            })
+        });
+        mock_mission_viewing_repository
+            .expect_mission_tags()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        // Victory Monument, roughly 2 km north.
+        let filter = MissionFilter {
+            lat: Some(13.7649),
+            lng: Some(100.5383),
+            radius_km: Some(5.0),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await.unwrap();
+
+        let distance_km = result.items[0].distance_km.unwrap();
+        assert!((1.5..3.0).contains(&distance_km), "{distance_km}");
+        assert_eq!(result.items[0].venue.as_ref().unwrap().name, "Siam Paragon");
+    }
+
+    #[tokio::test]
+    async fn test_get_near_requires_all_parameters() {
+        let use_case = MissionViewingUseCase::new(Arc::new(MockMissionViewingRepository::new()));
+        let filter = MissionFilter {
+            lat: Some(13.7649),
+            lng: Some(100.5383),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_crew_roster_pages_by_join_time() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+        let now = chrono::Utc::now().naive_utc();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(move |mission_id| {
+                Box::pin(async move {
+                    Ok(MissionEntity {
+                        id: mission_id,
+                        name: "Test".to_string(),
+                        description: None,
+                        status: MissionStatuses::Open,
+                        chief_id: 1,
+                        created_at: now,
+                        updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
+                    })
+                })
+            });
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(3) }));
+        mock_mission_viewing_repository
+            .expect_crew_roster()
+            .withf(|mission_id, limit, offset| *mission_id == 1 && *limit == 2 && *offset == 2)
+            .returning(move |mission_id, _, _| {
+                Box::pin(async move {
+                    Ok(vec![(
+                        CrewMembershipEntity {
+                            mission_id,
+                            brawler_id: 7,
+                            joined_at: now,
+                            role: CrewRoles::CoChief.to_string(),
+                        },
+                        BrawlerProfileEntity {
+                            username: "third".to_string(),
+                            display_name: "Third".to_string(),
+                            avatar_url: None,
+                        },
+                    )])
+                })
+            });
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let page_query = PageQuery {
+            page: Some(2),
+            limit: Some(2),
+        };
+
+        let result = use_case.crew_roster(1, &page_query, &MissionViewer::default()).await.unwrap();
+
+        assert_eq!(result.total, 3);
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].username, "third");
+        assert_eq!(result.items[0].role, "CoChief");
+    }
+
+    #[tokio::test]
+    async fn test_crew_roster_rejects_oversized_limit() {
+        let use_case = MissionViewingUseCase::new(Arc::new(MockMissionViewingRepository::new()));
+        let page_query = PageQuery {
+            page: None,
+            limit: Some(1000),
+        };
+
+        let result = use_case.crew_roster(1, &page_query, &MissionViewer::default()).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_crew_roster_rejects_page_past_offset_range() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+        mock_mission_viewing_repository.expect_crew_roster().never();
+
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let page_query = PageQuery {
+            page: Some(i64::MAX),
+            limit: Some(20),
+        };
+
+        let result = use_case
+            .crew_roster(1, &page_query, &MissionViewer::default())
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_pages_through_results() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_count()
+            .returning(|_| Box::pin(async { Ok(25) }));
+        mock_mission_viewing_repository
+            .expect_get()
+            .withf(|_, limit, offset| *limit == 10 && *offset == 10)
+            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+        mock_mission_viewing_repository
+            .expect_mission_tags()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let filter = MissionFilter {
+            sort: Some(MissionSort::MostCrew),
+            page: Some(2),
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await.unwrap();
+
+        assert_eq!(result.total, 25);
+        assert_eq!(result.next_page, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_get_rejects_page_past_offset_range() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+        mock_mission_viewing_repository.expect_count().never();
+        mock_mission_viewing_repository.expect_get().never();
+
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let filter = MissionFilter {
+            page: Some(i64::MAX / 10),
+            limit: Some(100),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_paginated_last_page_with_huge_page_number() {
+        let paginated = Paginated::new(Vec::<i32>::new(), i64::MAX, 100, 5);
+
+        assert_eq!(paginated.next_page, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_distance_sort_requires_radius_search() {
+        let use_case = MissionViewingUseCase::new(Arc::new(MockMissionViewingRepository::new()));
+        let filter = MissionFilter {
+            sort: Some(MissionSort::Distance),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_search_returns_snippets() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_count()
+            .returning(|_| Box::pin(async { Ok(1) }));
+        mock_mission_viewing_repository
+            .expect_get()
+            .withf(|filter, _, _| filter.sort_by().unwrap() == MissionSort::Relevance)
+            .returning(|_, _, _| {
+                Box::pin(async {
+                    let now = chrono::Utc::now().naive_utc();
+                    Ok(vec![(
+                        MissionEntity {
+                            id: 1,
+                            name: "Board games".to_string(),
+                            description: Some("Bring your own board games".to_string()),
+                            status: MissionStatuses::Open,
+                            chief_id: 1,
+                            created_at: now,
+                            updated_at: now,
+                            starts_at: now,
+                            ends_at: now + chrono::Duration::hours(2),
+                            timezone: "UTC".to_string(),
+                            venue_name: None,
+                            venue_address: None,
+                            latitude: None,
+                            longitude: None,
+                            max_crew: 10,
+                            min_crew: 1,
+                            join_policy: JoinPolicy::Open.to_string(),
+                        },
+                        chief(),
+                        0,
+                        Some("<b>Board</b> games - Bring your own <b>board</b> games".to_string()),
+                    )])
+                })
+            });
+        mock_mission_viewing_repository
+            .expect_mission_tags()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let filter = MissionFilter {
+            q: Some(" board ".to_string()),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await.unwrap();
+
+        assert!(result.items[0].snippet.as_ref().unwrap().contains("<b>Board</b>"));
+    }
+
+    #[tokio::test]
+    async fn test_get_relevance_sort_requires_q() {
+        let use_case = MissionViewingUseCase::new(Arc::new(MockMissionViewingRepository::new()));
+        let filter = MissionFilter {
+            q: Some("   ".to_string()),
+            sort: Some(MissionSort::Relevance),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_popular_tags() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_popular_tags()
+            .withf(|limit| *limit == 5)
+            .returning(|_| {
+                Box::pin(async { Ok(vec![("chess".to_string(), 4), ("outdoor".to_string(), 2)]) })
+            });
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+
+        let result = use_case
+            .popular_tags(&PopularTagsQuery { limit: Some(5) })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                TagCountModel { name: "chess".to_string(), mission_count: 4 },
+                TagCountModel { name: "outdoor".to_string(), mission_count: 2 },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_rejects_invalid_tag_filter() {
+        let mock_mission_viewing_repository = MockMissionViewingRepository::new();
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let filter = MissionFilter {
+            all_tags: Some("chess,board_games".to_string()),
+            ..Default::default()
+        };
+
+        let result = use_case.get(&filter).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_status_history() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|mission_id| {
+                let now = chrono::Utc::now().naive_utc();
+                Box::pin(async move {
+                    Ok(MissionEntity {
+                        id: mission_id,
+                        name: "Test".to_string(),
+                        description: None,
+                        status: MissionStatuses::Failed,
+                        chief_id: 1,
+                        created_at: now,
+                        updated_at: now,
+                        starts_at: now,
+                        ends_at: now + chrono::Duration::hours(2),
+                        timezone: "UTC".to_string(),
+                        venue_name: None,
+                        venue_address: None,
+                        latitude: None,
+                        longitude: None,
+                        max_crew: 10,
+                        min_crew: 1,
+                        join_policy: JoinPolicy::Open.to_string(),
+                    })
+                })
+            });
+        mock_mission_viewing_repository
+            .expect_status_history()
+            .withf(|mission_id| *mission_id == 7)
+            .returning(|mission_id| {
+                let now = chrono::Utc::now().naive_utc();
+                Box::pin(async move {
+                    Ok(vec![
+                        (
+                            MissionStatusEventEntity {
+                                id: 1,
+                                mission_id,
+                                from_status: MissionStatuses::Open,
+                                to_status: MissionStatuses::InProgress,
+                                actor_id: 1,
+                                reason: None,
+                                created_at: now,
+                            },
+                            chief(),
+                        ),
+                        (
+                            MissionStatusEventEntity {
+                                id: 2,
+                                mission_id,
+                                from_status: MissionStatuses::InProgress,
+                                to_status: MissionStatuses::Failed,
+                                actor_id: 1,
+                                reason: Some("Venue closed".to_string()),
+                                created_at: now,
+                            },
+                            chief(),
+                        ),
+                    ])
+                })
+            });
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+
+        let result = use_case.status_history(7, &MissionViewer::default()).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].to_status, MissionStatuses::InProgress);
+        assert_eq!(result[1].from_status, MissionStatuses::InProgress);
+        assert_eq!(result[1].actor_display_name, "Chief");
+        assert_eq!(result[1].reason.as_deref(), Some("Venue closed"));
+    }
+
+    #[tokio::test]
+    async fn test_view_details_hides_invite_only_mission_from_outsiders() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|mission_id| Box::pin(async move { Ok(invite_only_mission(mission_id)) }));
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        mock_mission_viewing_repository
+            .expect_is_waitlisted()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+        mock_mission_viewing_repository.expect_chief_profile().never();
+
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+
+        let anonymous = use_case.view_detail(5, &MissionViewer::default()).await;
+        let outsider = use_case
+            .view_detail(
+                5,
+                &MissionViewer {
+                    brawler_id: Some(9),
+                    invite_token: None,
+                },
+            )
+            .await;
+
+        assert!(matches!(anonymous, Err(AppError::NotFound(_))));
+        assert!(matches!(outsider, Err(AppError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_crew_roster_of_invite_only_mission_for_crew_member() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|mission_id| Box::pin(async move { Ok(invite_only_mission(mission_id)) }));
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .withf(|mission_id, brawler_id| *mission_id == 5 && *brawler_id == 9)
+            .returning(|_, _| Box::pin(async { Ok(Some(CrewRoles::Member.to_string())) }));
+        mock_mission_viewing_repository
+            .expect_crew_counting()
+            .returning(|_| Box::pin(async { Ok(0) }));
+        mock_mission_viewing_repository
+            .expect_crew_roster()
+            .returning(|_, _, _| Box::pin(async { Ok(vec![]) }));
+
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let viewer = MissionViewer {
+            brawler_id: Some(9),
+            invite_token: None,
+        };
+
+        let result = use_case
+            .crew_roster(5, &PageQuery::default(), &viewer)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_invite_only_mission_with_invite_needs_sign_in() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|mission_id| Box::pin(async move { Ok(invite_only_mission(mission_id)) }));
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+        mock_mission_viewing_repository
+            .expect_is_waitlisted()
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+        mock_mission_viewing_repository
+            .expect_has_usable_invite()
+            .withf(|mission_id, token_hash| {
+                *mission_id == 5 && *token_hash == sha256_hex("abc123")
+            })
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+        mock_mission_viewing_repository
+            .expect_status_history()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+
+        let anonymous = use_case
+            .status_history(
+                5,
+                &MissionViewer {
+                    brawler_id: None,
+                    invite_token: Some("abc123".to_string()),
+                },
+            )
+            .await;
+        let invited = use_case
+            .status_history(
+                5,
+                &MissionViewer {
+                    brawler_id: Some(9),
+                    invite_token: Some("abc123".to_string()),
+                },
+            )
+            .await;
+
+        assert!(matches!(anonymous, Err(AppError::Unauthorized(_))));
+        assert!(invited.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_draft_is_only_visible_to_leaders() {
+        let mut mock_mission_viewing_repository = MockMissionViewingRepository::new();
+
+        mock_mission_viewing_repository
+            .expect_view_detail()
+            .returning(|mission_id| {
+                Box::pin(async move {
+                    Ok(MissionEntity {
+                        status: MissionStatuses::Draft,
+                        join_policy: JoinPolicy::Open.to_string(),
+                        ..invite_only_mission(mission_id)
+                    })
+                })
+            });
+        mock_mission_viewing_repository
+            .expect_crew_role()
+            .returning(|_, brawler_id| {
+                Box::pin(async move {
+                    Ok((brawler_id == 3).then(|| CrewRoles::CoChief.to_string()))
+                })
+            });
+        mock_mission_viewing_repository
+            .expect_status_history()
+            .times(2)
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+
+        let use_case = MissionViewingUseCase::new(Arc::new(mock_mission_viewing_repository));
+        let viewer = |brawler_id: Option<i32>| MissionViewer {
+            brawler_id,
+            invite_token: None,
+        };
+
+        let chief = use_case.status_history(5, &viewer(Some(1))).await;
+        let co_chief = use_case.status_history(5, &viewer(Some(3))).await;
+        let member = use_case.status_history(5, &viewer(Some(9))).await;
+        let anonymous = use_case.status_history(5, &viewer(None)).await;
+
+        assert!(chief.is_ok());
+        assert!(co_chief.is_ok());
+        assert!(matches!(member, Err(AppError::NotFound(_))));
+        assert!(matches!(anonymous, Err(AppError::NotFound(_))));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        app_error::{AppError, AppResult},
+        entities::password_reset_tokens::NewPasswordResetToken,
+        repositories::{
+            brawlers::BrawlerRepository, mailer::Mailer, password_reset::PasswordResetRepository,
+            sessions::SessionRepository,
+        },
+        value_objects::{
+            mail_message::MailMessage,
+            password_model::{ChangePasswordModel, ForgotPasswordModel, ResetPasswordModel},
+        },
+    },
+    infrastructure::{
+        argon2::{hash, verify},
+        sha256::sha256_hex,
+    },
+};
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+pub const PASSWORD_RESET_TOKEN_TTL_MINUTES: i64 = 30;
+
+pub struct PasswordUseCase<T1, T2, T3, T4>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: Mailer + Send + Sync + ?Sized,
+{
+    brawler_repository: Arc<T1>,
+    password_reset_repository: Arc<T2>,
+    session_repository: Arc<T3>,
+    mailer: Arc<T4>,
+    password_reset_url: String,
+}
+
+impl<T1, T2, T3, T4> PasswordUseCase<T1, T2, T3, T4>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: Mailer + Send + Sync + ?Sized,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        password_reset_repository: Arc<T2>,
+        session_repository: Arc<T3>,
+        mailer: Arc<T4>,
+        password_reset_url: String,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            password_reset_repository,
+            session_repository,
+            mailer,
+            password_reset_url,
+        }
+    }
+
+    pub async fn change_password(
+        &self,
+        brawler_id: i32,
+        change_password_model: ChangePasswordModel,
+    ) -> AppResult<()> {
+        validate_password(&change_password_model.new_password)?;
+
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+
+        if !verify(change_password_model.old_password, brawler.password)? {
+            return Err(AppError::Validation("Old password is incorrect".to_string()));
+        }
+
+        let hashed_password = hash(change_password_model.new_password)?;
+        self.brawler_repository
+            .update_password(brawler_id, hashed_password)
+            .await?;
+
+        // Every device has to login again with the new password.
+        self.session_repository.revoke_all(brawler_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn forgot_password(&self, forgot_password_model: ForgotPasswordModel) -> AppResult<()> {
+        // Unknown usernames are not reported back, so this cannot be used to probe for accounts.
+        let brawler = match self
+            .brawler_repository
+            .find_by_username(forgot_password_model.username)
+            .await
+        {
+            Ok(brawler) => brawler,
+            Err(e)
+                if matches!(
+                    e.downcast_ref::<diesel::result::Error>(),
+                    Some(diesel::result::Error::NotFound)
+                ) =>
+            {
+                return Ok(());
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let Some(email) = brawler.email else {
+            warn!("Brawler {} has no email, skip password reset mail", brawler.id);
+            return Ok(());
+        };
+
+        let token = Uuid::new_v4().simple().to_string();
+
+        self.password_reset_repository
+            .create(NewPasswordResetToken {
+                brawler_id: brawler.id,
+                token_hash: sha256_hex(&token),
+                expires_at: (Utc::now() + Duration::minutes(PASSWORD_RESET_TOKEN_TTL_MINUTES))
+                    .naive_utc(),
+            })
+            .await?;
+
+        // A failed send is only logged: answering differently would tell the caller that
+        // the account exists and has an email.
+        if let Err(e) = self
+            .mailer
+            .send(MailMessage {
+                to: email,
+                subject: "Reset your meetup password".to_string(),
+                body: format!(
+                    "Hi {},\n\nUse the link below to reset your password. It expires in {} minutes and can only be used once.\n\n{}?token={}\n\nIf you did not ask for this, just ignore this mail.",
+                    brawler.username, PASSWORD_RESET_TOKEN_TTL_MINUTES, self.password_reset_url, token
+                ),
+            })
+            .await
+        {
+            warn!("Failed to send password reset mail to brawler {}: {}", brawler.id, e);
+        }
+
+        Ok(())
+    }
+
+    pub async fn reset_password(&self, reset_password_model: ResetPasswordModel) -> AppResult<()> {
+        validate_password(&reset_password_model.new_password)?;
+
+        let hashed_password = hash(reset_password_model.new_password)?;
+        self.password_reset_repository
+            .reset_password(sha256_hex(&reset_password_model.token), hashed_password)
+            .await?
+            .ok_or_else(|| AppError::Validation("Invalid or expired reset token".to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn validate_password(password: &str) -> AppResult<()> {
+    if password.len() < MIN_PASSWORD_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Password is too short (min {} characters)",
+            MIN_PASSWORD_LENGTH
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Utc;
+
+    use crate::{
+        application::use_cases::password::PasswordUseCase,
+        domain::{
+            entities::brawlers::BrawlerEntity,
+            repositories::{
+                brawlers::MockBrawlerRepository, mailer::MockMailer,
+                password_reset::MockPasswordResetRepository, sessions::MockSessionRepository,
+            },
+            value_objects::password_model::{
+                ChangePasswordModel, ForgotPasswordModel, ResetPasswordModel,
+            },
+        },
+        infrastructure::{argon2::hash, mailer::file::FileMailer, sha256::sha256_hex},
+    };
+
+    fn brawler(email: Option<String>) -> BrawlerEntity {
+        let now = Utc::now().naive_utc();
+        BrawlerEntity {
+            id: 1,
+            username: "menta".to_string(),
+            password: hash("P@ssw0rd".to_string()).unwrap(),
+            created_at: now,
+            updated_at: now,
+            email,
+            totp_secret: None,
+            totp_enabled: false,
+            display_name: "Menta".to_string(),
+            avatar_url: None,
+            avatar_public_id: None,
+            totp_failed_attempts: 0,
+            totp_locked_until: None,
+            totp_last_step: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_change_password() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mut mock_session_repository = MockSessionRepository::new();
+        let mock_mailer = MockMailer::new();
+
+        mock_brawler_repository
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(brawler(None)) }));
+        mock_brawler_repository
+            .expect_update_password()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_session_repository
+            .expect_revoke_all()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .change_password(
+                1,
+                ChangePasswordModel {
+                    old_password: "P@ssw0rd".to_string(),
+                    new_password: "N3wP@ssw0rd".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_change_password_wrong_old_password() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+        let mock_mailer = MockMailer::new();
+
+        mock_brawler_repository
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(brawler(None)) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .change_password(
+                1,
+                ChangePasswordModel {
+                    old_password: "wrong".to_string(),
+                    new_password: "N3wP@ssw0rd".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Old password is incorrect");
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_sends_mail() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+        let mut mock_mailer = MockMailer::new();
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Ok(brawler(Some("menta@meetup.local".to_string()))) }));
+        mock_password_reset_repository
+            .expect_create()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_mailer
+            .expect_send()
+            .withf(|message| {
+                message.to == "menta@meetup.local"
+                    && message.body.contains("http://localhost/reset-password?token=")
+            })
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .forgot_password(ForgotPasswordModel {
+                username: "menta".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_mailer_failure_is_not_reported() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+        let mut mock_mailer = MockMailer::new();
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Ok(brawler(Some("menta@meetup.local".to_string()))) }));
+        mock_password_reset_repository
+            .expect_create()
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_mailer
+            .expect_send()
+            .returning(|_| Box::pin(async { Err(anyhow::anyhow!("SMTP is down")) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .forgot_password(ForgotPasswordModel {
+                username: "menta".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_unknown_username() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+        let mock_mailer = MockMailer::new();
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Err(anyhow::Error::from(diesel::result::Error::NotFound)) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .forgot_password(ForgotPasswordModel {
+                username: "nobody".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_forgot_password_with_file_mailer() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+
+        let outbox_dir = std::env::temp_dir().join(format!("meetup-outbox-{}", uuid::Uuid::new_v4()));
+
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Ok(brawler(Some("menta@meetup.local".to_string()))) }));
+        mock_password_reset_repository
+            .expect_create()
+            .returning(|_| Box::pin(async { Ok(()) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(FileMailer::new(outbox_dir.to_str().unwrap())),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        use_case
+            .forgot_password(ForgotPasswordModel {
+                username: "menta".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let mails: Vec<_> = std::fs::read_dir(&outbox_dir).unwrap().collect();
+        assert_eq!(mails.len(), 1);
+
+        std::fs::remove_dir_all(outbox_dir).ok();
+    }
+
+    #[tokio::test]
+    async fn test_reset_password() {
+        let mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+        let mock_mailer = MockMailer::new();
+
+        let token_hash = sha256_hex("reset-token");
+        mock_password_reset_repository
+            .expect_reset_password()
+            .withf(move |hash, hashed_password| *hash == token_hash && hashed_password != "N3wP@ssw0rd")
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(Some(1)) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .reset_password(ResetPasswordModel {
+                token: "reset-token".to_string(),
+                new_password: "N3wP@ssw0rd".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reset_password_invalid_token() {
+        let mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_password_reset_repository = MockPasswordResetRepository::new();
+        let mock_session_repository = MockSessionRepository::new();
+        let mock_mailer = MockMailer::new();
+
+        mock_password_reset_repository
+            .expect_reset_password()
+            .returning(|_, _| Box::pin(async { Ok(None) }));
+
+        let use_case = PasswordUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_password_reset_repository),
+            Arc::new(mock_session_repository),
+            Arc::new(mock_mailer),
+            "http://localhost/reset-password".to_string(),
+        );
+
+        let result = use_case
+            .reset_password(ResetPasswordModel {
+                token: "used-token".to_string(),
+                new_password: "N3wP@ssw0rd".to_string(),
+            })
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Invalid or expired reset token");
+    }
+}
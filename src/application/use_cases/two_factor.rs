@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        app_error::{AppError, AppResult},
+        entities::{brawlers::BrawlerEntity, recovery_codes::NewRecoveryCode},
+        repositories::{brawlers::BrawlerRepository, recovery_codes::RecoveryCodeRepository},
+        value_objects::two_factor_model::{RecoveryCodesModel, TotpCodeModel, TotpEnrollmentModel},
+    },
+    infrastructure::{argon2, totp},
+};
+
+pub const RECOVERY_CODE_COUNT: usize = 10;
+pub const MAX_SECOND_FACTOR_ATTEMPTS: i32 = 5;
+pub const SECOND_FACTOR_LOCKOUT_MINUTES: i64 = 15;
+
+pub struct TwoFactorUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RecoveryCodeRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    recovery_code_repository: Arc<T2>,
+}
+
+impl<T1, T2> TwoFactorUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RecoveryCodeRepository + Send + Sync,
+{
+    pub fn new(brawler_repository: Arc<T1>, recovery_code_repository: Arc<T2>) -> Self {
+        Self {
+            brawler_repository,
+            recovery_code_repository,
+        }
+    }
+
+    /// Stores a fresh secret without enabling 2FA yet; it only becomes active once
+    /// the brawler proves their authenticator works through `verify`.
+    pub async fn enroll(&self, brawler_id: i32) -> AppResult<TotpEnrollmentModel> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler.totp_enabled {
+            return Err(AppError::Conflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        let secret = totp::generate_secret();
+        let otpauth_uri = totp::otpauth_uri(&secret, &brawler.username)?;
+
+        self.brawler_repository
+            .update_totp(brawler_id, Some(secret.clone()), false)
+            .await?;
+
+        Ok(TotpEnrollmentModel {
+            secret,
+            otpauth_uri,
+        })
+    }
+
+    pub async fn verify(
+        &self,
+        brawler_id: i32,
+        totp_code_model: TotpCodeModel,
+    ) -> AppResult<RecoveryCodesModel> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        if brawler.totp_enabled {
+            return Err(AppError::Conflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        let Some(secret) = brawler.totp_secret else {
+            return Err(AppError::Validation(
+                "Two-factor enrollment has not been started".to_string(),
+            ));
+        };
+
+        // Recording the step keeps the enrollment code from also being used to log in.
+        let accepted = match totp::matching_step(&secret, totp_code_model.code.trim())? {
+            Some(step) => self.brawler_repository.record_totp_step(brawler_id, step).await?,
+            None => false,
+        };
+        if !accepted {
+            return Err(AppError::Validation("Invalid two-factor code".to_string()));
+        }
+
+        let recovery_codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| generate_recovery_code())
+            .collect();
+
+        let new_recovery_codes = recovery_codes
+            .iter()
+            .map(|code| {
+                Ok(NewRecoveryCode {
+                    brawler_id,
+                    code_hash: argon2::hash(normalize_recovery_code(code))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.recovery_code_repository
+            .replace_all(brawler_id, new_recovery_codes)
+            .await?;
+
+        self.brawler_repository
+            .update_totp(brawler_id, Some(secret), true)
+            .await?;
+
+        Ok(RecoveryCodesModel { recovery_codes })
+    }
+
+    pub async fn disable(&self, brawler_id: i32, totp_code_model: TotpCodeModel) -> AppResult<()> {
+        let brawler = self.brawler_repository.find_by_id(brawler_id).await?;
+        if !brawler.totp_enabled {
+            return Err(AppError::Conflict(
+                "Two-factor authentication is not enabled".to_string(),
+            ));
+        }
+
+        if !verify_second_factor(
+            self.brawler_repository.as_ref(),
+            self.recovery_code_repository.as_ref(),
+            &brawler,
+            &totp_code_model.code,
+        )
+        .await?
+        {
+            return Err(AppError::Validation("Invalid two-factor code".to_string()));
+        }
+
+        self.recovery_code_repository.delete_all(brawler_id).await?;
+        self.brawler_repository
+            .update_totp(brawler_id, None, false)
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Accepts either a current TOTP code or one of the unused recovery codes.
+/// A matching recovery code is burned so it cannot be used again, and a TOTP code
+/// is refused once its time step has been accepted. After `MAX_SECOND_FACTOR_ATTEMPTS`
+/// wrong codes in a row, every code is refused for `SECOND_FACTOR_LOCKOUT_MINUTES`.
+pub async fn verify_second_factor<T1, T2>(
+    brawler_repository: &T1,
+    recovery_code_repository: &T2,
+    brawler: &BrawlerEntity,
+    code: &str,
+) -> AppResult<bool>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RecoveryCodeRepository + Send + Sync,
+{
+    let now = Utc::now().naive_utc();
+    if brawler.totp_locked_until.is_some_and(|locked_until| locked_until > now) {
+        return Err(AppError::Unauthorized(
+            "Too many failed two-factor attempts, try again later".to_string(),
+        ));
+    }
+
+    let code = code.trim();
+
+    if let Some(secret) = &brawler.totp_secret
+        && let Some(step) = totp::matching_step(secret, code)?
+        && brawler_repository.record_totp_step(brawler.id, step).await?
+    {
+        brawler_repository.reset_second_factor_failures(brawler.id).await?;
+        return Ok(true);
+    }
+
+    let normalized = normalize_recovery_code(code);
+    for recovery_code in recovery_code_repository.find_unused(brawler.id).await? {
+        if argon2::verify(normalized.clone(), recovery_code.code_hash)? {
+            recovery_code_repository.mark_used(recovery_code.id).await?;
+            brawler_repository.reset_second_factor_failures(brawler.id).await?;
+            return Ok(true);
+        }
+    }
+
+    brawler_repository
+        .record_second_factor_failure(
+            brawler.id,
+            MAX_SECOND_FACTOR_ATTEMPTS,
+            now + Duration::minutes(SECOND_FACTOR_LOCKOUT_MINUTES),
+        )
+        .await?;
+
+    Ok(false)
+}
+
+// Formatted as xxxxx-xxxxx to be easier to copy down; the dash is optional on input.
+fn generate_recovery_code() -> String {
+    let raw = Uuid::new_v4().simple().to_string();
+    format!("{}-{}", &raw[..5], &raw[5..10])
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.trim().replace('-', "").to_lowercase()
+}
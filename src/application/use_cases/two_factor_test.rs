@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+
+    use crate::{
+        application::use_cases::two_factor::{
+            MAX_SECOND_FACTOR_ATTEMPTS, RECOVERY_CODE_COUNT, TwoFactorUseCase,
+        },
+        domain::{
+            app_error::AppError,
+            entities::{brawlers::BrawlerEntity, recovery_codes::RecoveryCodeEntity},
+            repositories::{
+                brawlers::MockBrawlerRepository, recovery_codes::MockRecoveryCodeRepository,
+            },
+            value_objects::two_factor_model::TotpCodeModel,
+        },
+        infrastructure::{argon2::hash, totp},
+    };
+
+    fn brawler(totp_secret: Option<String>, totp_enabled: bool) -> BrawlerEntity {
+        let now = Utc::now().naive_utc();
+        BrawlerEntity {
+            id: 1,
+            username: "menta".to_string(),
+            password: hash("P@ssw0rd".to_string()).unwrap(),
+            created_at: now,
+            updated_at: now,
+            email: None,
+            totp_secret,
+            totp_enabled,
+            display_name: "Menta".to_string(),
+            avatar_url: None,
+            avatar_public_id: None,
+            totp_failed_attempts: 0,
+            totp_locked_until: None,
+            totp_last_step: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enroll() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        mock_brawler_repository
+            .expect_find_by_id()
+            .returning(|_| Box::pin(async { Ok(brawler(None, false)) }));
+        mock_brawler_repository
+            .expect_update_totp()
+            .withf(|id, secret, enabled| *id == 1 && secret.is_some() && !*enabled)
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = TwoFactorUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let enrollment = use_case.enroll(1).await.unwrap();
+
+        assert!(enrollment.otpauth_uri.starts_with("otpauth://totp/Meetup:menta"));
+        assert!(enrollment.otpauth_uri.contains(&enrollment.secret));
+    }
+
+    #[tokio::test]
+    async fn test_verify_enables_and_returns_recovery_codes() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        let secret = totp::generate_secret();
+        let code = totp::generate_current(&secret).unwrap();
+
+        let pending_secret = secret.clone();
+        mock_brawler_repository.expect_find_by_id().returning(move |_| {
+            let secret = pending_secret.clone();
+            Box::pin(async move { Ok(brawler(Some(secret), false)) })
+        });
+        mock_brawler_repository
+            .expect_record_totp_step()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(true) }));
+        mock_recovery_code_repository
+            .expect_replace_all()
+            .withf(|_, codes| codes.len() == RECOVERY_CODE_COUNT)
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(()) }));
+        mock_brawler_repository
+            .expect_update_totp()
+            .withf(move |_, saved_secret, enabled| {
+                saved_secret.as_deref() == Some(secret.as_str()) && *enabled
+            })
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = TwoFactorUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let result = use_case.verify(1, TotpCodeModel { code }).await.unwrap();
+
+        assert_eq!(result.recovery_codes.len(), RECOVERY_CODE_COUNT);
+    }
+
+    #[tokio::test]
+    async fn test_verify_wrong_code() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        mock_brawler_repository.expect_find_by_id().returning(|_| {
+            Box::pin(async { Ok(brawler(Some(totp::generate_secret()), false)) })
+        });
+
+        let use_case = TwoFactorUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let result = use_case
+            .verify(
+                1,
+                TotpCodeModel {
+                    code: "12345".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "Invalid two-factor code");
+    }
+
+    #[tokio::test]
+    async fn test_disable_with_recovery_code() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        mock_brawler_repository.expect_find_by_id().returning(|_| {
+            Box::pin(async { Ok(brawler(Some(totp::generate_secret()), true)) })
+        });
+        mock_recovery_code_repository.expect_find_unused().returning(|_| {
+            Box::pin(async {
+                Ok(vec![RecoveryCodeEntity {
+                    id: 7,
+                    brawler_id: 1,
+                    code_hash: hash("abcde12345".to_string()).unwrap(),
+                    created_at: Utc::now().naive_utc(),
+                    used_at: None,
+                }])
+            })
+        });
+        mock_recovery_code_repository
+            .expect_mark_used()
+            .withf(|id| *id == 7)
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_brawler_repository
+            .expect_reset_second_factor_failures()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_recovery_code_repository
+            .expect_delete_all()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(()) }));
+        mock_brawler_repository
+            .expect_update_totp()
+            .withf(|_, secret, enabled| secret.is_none() && !*enabled)
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+
+        let use_case = TwoFactorUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let result = use_case
+            .disable(
+                1,
+                TotpCodeModel {
+                    code: "ABCDE-12345".to_string(),
+                },
+            )
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_disable_rejects_replayed_totp_code() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        let secret = totp::generate_secret();
+        let code = totp::generate_current(&secret).unwrap();
+
+        mock_brawler_repository.expect_find_by_id().returning(move |_| {
+            let secret = secret.clone();
+            Box::pin(async move { Ok(brawler(Some(secret), true)) })
+        });
+        // The step was already accepted, so the conditional update matches nothing.
+        mock_brawler_repository
+            .expect_record_totp_step()
+            .times(1)
+            .returning(|_, _| Box::pin(async { Ok(false) }));
+        mock_recovery_code_repository
+            .expect_find_unused()
+            .returning(|_| Box::pin(async { Ok(vec![]) }));
+        mock_brawler_repository
+            .expect_record_second_factor_failure()
+            .withf(|id, max_attempts, _| *id == 1 && *max_attempts == MAX_SECOND_FACTOR_ATTEMPTS)
+            .times(1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }));
+        mock_brawler_repository.expect_update_totp().never();
+
+        let use_case = TwoFactorUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let result = use_case.disable(1, TotpCodeModel { code }).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_disable_while_locked_out() {
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        let mut mock_recovery_code_repository = MockRecoveryCodeRepository::new();
+
+        let secret = totp::generate_secret();
+        let code = totp::generate_current(&secret).unwrap();
+
+        mock_brawler_repository.expect_find_by_id().returning(move |_| {
+            let locked = BrawlerEntity {
+                totp_locked_until: Some(Utc::now().naive_utc() + Duration::minutes(10)),
+                ..brawler(Some(secret.clone()), true)
+            };
+            Box::pin(async move { Ok(locked) })
+        });
+        // Even a valid code is not looked at until the lockout ends.
+        mock_brawler_repository.expect_record_totp_step().never();
+        mock_recovery_code_repository.expect_find_unused().never();
+        mock_brawler_repository.expect_update_totp().never();
+
+        let use_case = TwoFactorUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(mock_recovery_code_repository),
+        );
+
+        let result = use_case.disable(1, TotpCodeModel { code }).await;
+
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+}
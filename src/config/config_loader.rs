@@ -1,7 +1,10 @@
 use anyhow::Result;
 
 use crate::config::{
-    config_model::{Database, DotEnvyConfig, Server},
+    config_model::{
+        CloudinaryEnv, Database, DotEnvyConfig, ImageStorageEnv, JwtEnv, JwtKeyEnv, MailerEnv,
+        Server, SmtpEnv,
+    },
     stage::Stage,
 };
 
@@ -26,14 +29,12 @@ pub fn load() -> Result<DotEnvyConfig> {
             .parse()?,
     };
 
-    let secret = std::env::var("JWT_USER_SECRET")
-        .expect("SECRET is valid")
-        .parse()?;
+    let jwt = get_jwt_env()?;
 
     let config = DotEnvyConfig {
         server,
         database,
-        secret,
+        jwt,
     };
 
     Ok(config)
@@ -46,6 +47,81 @@ pub fn get_stage() -> Stage {
     Stage::try_form(&stage_str).unwrap_or_default()
 }
 
-pub fn get_jwt_env() -> Result<String> {
-    std::env::var("JWT_USER_SECRET").map_err(|_| anyhow::anyhow!("JWT_USER_SECRET not set"))
+pub fn get_jwt_env() -> Result<JwtEnv> {
+    dotenvy::dotenv().ok();
+
+    // JWT_PUBLIC_KEYS is a comma separated list of `kid=path/to/public.pem`.
+    let public_keys = std::env::var("JWT_PUBLIC_KEYS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (kid, path) = entry
+                .split_once('=')
+                .ok_or_else(|| anyhow::anyhow!("Invalid JWT_PUBLIC_KEYS entry: {}", entry))?;
+            Ok(JwtKeyEnv {
+                kid: kid.trim().to_string(),
+                public_key_path: path.trim().to_string(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(JwtEnv {
+        algorithm: std::env::var("JWT_ALGORITHM").unwrap_or("HS256".to_string()),
+        secret: std::env::var("JWT_USER_SECRET").ok(),
+        signing_key_id: std::env::var("JWT_SIGNING_KEY_ID").ok(),
+        private_key_path: std::env::var("JWT_PRIVATE_KEY_PATH").ok(),
+        public_keys,
+    })
+}
+
+pub fn get_mailer_env() -> Result<MailerEnv> {
+    dotenvy::dotenv().ok();
+
+    // Without SMTP_HOST the mails are written to MAIL_OUTBOX_DIR instead of being sent.
+    let smtp = match std::env::var("SMTP_HOST") {
+        Ok(host) => Some(SmtpEnv {
+            host,
+            port: std::env::var("SMTP_PORT")
+                .unwrap_or("587".to_string())
+                .parse()?,
+            username: std::env::var("SMTP_USERNAME")
+                .map_err(|_| anyhow::anyhow!("SMTP_USERNAME not set"))?,
+            password: std::env::var("SMTP_PASSWORD")
+                .map_err(|_| anyhow::anyhow!("SMTP_PASSWORD not set"))?,
+        }),
+        Err(_) => None,
+    };
+
+    Ok(MailerEnv {
+        from: std::env::var("MAIL_FROM").map_err(|_| anyhow::anyhow!("MAIL_FROM not set"))?,
+        smtp,
+        outbox_dir: std::env::var("MAIL_OUTBOX_DIR").unwrap_or("outbox".to_string()),
+        password_reset_url: std::env::var("PASSWORD_RESET_URL")
+            .map_err(|_| anyhow::anyhow!("PASSWORD_RESET_URL not set"))?,
+    })
+}
+
+pub fn get_image_storage_env() -> Result<ImageStorageEnv> {
+    dotenvy::dotenv().ok();
+
+    // Without CLOUDINARY_CLOUD_NAME the images are written under IMAGE_LOCAL_DIR,
+    // which has to live inside `statics` to be served.
+    let cloudinary = match std::env::var("CLOUDINARY_CLOUD_NAME") {
+        Ok(cloud_name) => Some(CloudinaryEnv {
+            cloud_name,
+            api_key: std::env::var("CLOUDINARY_API_KEY")
+                .map_err(|_| anyhow::anyhow!("CLOUDINARY_API_KEY not set"))?,
+            api_secret: std::env::var("CLOUDINARY_API_SECRET")
+                .map_err(|_| anyhow::anyhow!("CLOUDINARY_API_SECRET not set"))?,
+        }),
+        Err(_) => None,
+    };
+
+    Ok(ImageStorageEnv {
+        cloudinary,
+        local_dir: std::env::var("IMAGE_LOCAL_DIR").unwrap_or("statics/uploads".to_string()),
+        local_base_url: std::env::var("IMAGE_LOCAL_BASE_URL").unwrap_or("/uploads".to_string()),
+    })
 }
@@ -14,5 +14,50 @@ pub struct Database {
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
-    pub secret: String,
+    pub jwt: JwtEnv,
+}
+
+#[derive(Debug, Clone)]
+pub struct JwtKeyEnv {
+    pub kid: String,
+    pub public_key_path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct JwtEnv {
+    pub algorithm: String,
+    pub secret: Option<String>,
+    pub signing_key_id: Option<String>,
+    pub private_key_path: Option<String>,
+    pub public_keys: Vec<JwtKeyEnv>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SmtpEnv {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MailerEnv {
+    pub from: String,
+    pub smtp: Option<SmtpEnv>,
+    pub outbox_dir: String,
+    pub password_reset_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CloudinaryEnv {
+    pub cloud_name: String,
+    pub api_key: String,
+    pub api_secret: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImageStorageEnv {
+    pub cloudinary: Option<CloudinaryEnv>,
+    pub local_dir: String,
+    pub local_base_url: String,
 }
@@ -0,0 +1,77 @@
+use std::fmt;
+
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+pub type AppResult<T> = Result<T, AppError>;
+
+/// Errors the use cases hand back to the http layer. Each variant maps to one status
+/// code and one stable machine-readable `code`.
+#[derive(Debug)]
+pub enum AppError {
+    NotFound(String),
+    Forbidden(String),
+    Conflict(String),
+    Validation(String),
+    Unauthorized(String),
+    Internal(anyhow::Error),
+}
+
+impl AppError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "not_found",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::Conflict(_) => "conflict",
+            AppError::Validation(_) => "validation_failed",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Internal(_) => "internal_error",
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::NotFound(message)
+            | AppError::Forbidden(message)
+            | AppError::Conflict(message)
+            | AppError::Validation(message)
+            | AppError::Unauthorized(message) => write!(f, "{}", message),
+            AppError::Internal(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::Internal(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+// Repositories and infrastructure helpers keep returning `anyhow::Error`; the few
+// database errors that mean something to a client are recognised here.
+impl From<anyhow::Error> for AppError {
+    fn from(e: anyhow::Error) -> Self {
+        let e = match e.downcast::<AppError>() {
+            Ok(app_error) => return app_error,
+            Err(e) => e,
+        };
+
+        match e.downcast_ref::<DieselError>() {
+            Some(DieselError::NotFound) => AppError::NotFound("Record not found".to_string()),
+            Some(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _)) => {
+                AppError::Conflict("Record already exists".to_string())
+            }
+            _ => AppError::Internal(e),
+        }
+    }
+}
+
+impl From<DieselError> for AppError {
+    fn from(e: DieselError) -> Self {
+        AppError::from(anyhow::Error::from(e))
+    }
+}
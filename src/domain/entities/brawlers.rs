@@ -10,6 +10,26 @@ pub struct BrawlerEntity {
     pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub email: Option<String>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled: bool,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub avatar_public_id: Option<String>,
+    /// Wrong second-factor codes since the last success or lockout.
+    pub totp_failed_attempts: i32,
+    pub totp_locked_until: Option<NaiveDateTime>,
+    /// The last TOTP time step accepted, so a code cannot be replayed within its window.
+    pub totp_last_step: Option<i64>,
+}
+
+/// The public face of a brawler, safe to embed in mission responses.
+#[derive(Debug, Clone, Queryable, Selectable)]
+#[diesel(table_name = brawlers)]
+pub struct BrawlerProfileEntity {
+    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -17,4 +37,6 @@ pub struct BrawlerEntity {
 pub struct RegisterBrawlerEntity {
     pub username: String,
     pub password: String,
+    pub email: Option<String>,
+    pub display_name: String,
 }
\ No newline at end of file
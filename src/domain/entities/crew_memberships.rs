@@ -1,18 +1,50 @@
+use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::entities::{brawlers::BrawlerEntity, missions::MissionEntity},
+    domain::{
+        entities::{
+            brawlers::{BrawlerEntity, BrawlerProfileEntity},
+            missions::MissionEntity,
+        },
+        value_objects::crew_model::CrewMemberModel,
+    },
     infrastructure::database::schema::crew_memberships,
 };
 
-pub const MAX_CREW_MEMBERSHIPS_PER_MISSION: u32 = 10;
+/// Used when a chief does not pick a capacity.
+pub const DEFAULT_MAX_CREW: i32 = 10;
+/// Upper bound a chief may set `max_crew` to.
+pub const MAX_CREW_LIMIT: i32 = 100;
 
-#[derive(Debug, Clone, Queryable, Insertable,Associations, Serialize,Selectable, Deserialize)]
+#[derive(Debug, Clone, Queryable, Associations, Selectable)]
 #[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
 #[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
 #[diesel(table_name = crew_memberships)]
 pub struct CrewMembershipEntity {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub joined_at: NaiveDateTime,
+    pub role: String,
+}
+
+impl CrewMembershipEntity {
+    pub fn to_model(&self, brawler: &BrawlerProfileEntity) -> CrewMemberModel {
+        CrewMemberModel {
+            brawler_id: self.brawler_id,
+            username: brawler.username.clone(),
+            display_name: brawler.display_name.clone(),
+            avatar_url: brawler.avatar_url.clone(),
+            joined_at: self.joined_at,
+            role: self.role.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = crew_memberships)]
+pub struct NewCrewMembership {
     pub brawler_id: i32,
     pub mission_id: i32,
-}   
\ No newline at end of file
+}
@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::{
+            brawlers::{BrawlerEntity, BrawlerProfileEntity},
+            missions::MissionEntity,
+        },
+        value_objects::join_request_model::JoinRequestModel,
+    },
+    infrastructure::database::schema::join_requests,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = join_requests)]
+pub struct JoinRequestEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+    pub decided_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = join_requests)]
+pub struct NewJoinRequest {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub status: String,
+}
+
+impl JoinRequestEntity {
+    pub fn to_model(&self, brawler: &BrawlerProfileEntity) -> JoinRequestModel {
+        JoinRequestModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            brawler_id: self.brawler_id,
+            display_name: brawler.display_name.clone(),
+            avatar_url: brawler.avatar_url.clone(),
+            status: self.status.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_bans;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_bans)]
+pub struct NewMissionBan {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub banned_by: i32,
+}
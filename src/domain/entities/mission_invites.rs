@@ -0,0 +1,49 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::{brawlers::BrawlerEntity, missions::MissionEntity},
+        value_objects::mission_invite_model::MissionInviteModel,
+    },
+    infrastructure::database::schema::mission_invites,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = created_by))]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = mission_invites)]
+pub struct MissionInviteEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub created_by: i32,
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: Option<NaiveDateTime>,
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+    pub revoked_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_invites)]
+pub struct NewMissionInvite {
+    pub mission_id: i32,
+    pub created_by: i32,
+    pub token_hash: String,
+    pub expires_at: Option<NaiveDateTime>,
+    pub max_uses: Option<i32>,
+}
+
+impl MissionInviteEntity {
+    pub fn to_model(&self) -> MissionInviteModel {
+        MissionInviteModel {
+            id: self.id,
+            mission_id: self.mission_id,
+            created_at: self.created_at.and_utc(),
+            expires_at: self.expires_at.map(|expires_at| expires_at.and_utc()),
+            max_uses: self.max_uses,
+            uses: self.uses,
+        }
+    }
+}
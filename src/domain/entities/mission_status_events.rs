@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::{
+        entities::{
+            brawlers::{BrawlerEntity, BrawlerProfileEntity},
+            missions::MissionEntity,
+        },
+        value_objects::{
+            mission_status_event_model::MissionStatusEventModel, mission_statuses::MissionStatuses,
+        },
+    },
+    infrastructure::database::schema::mission_status_events,
+};
+
+#[derive(Debug, Clone, Queryable, Associations, Selectable)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = actor_id))]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = mission_status_events)]
+pub struct MissionStatusEventEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub from_status: MissionStatuses,
+    pub to_status: MissionStatuses,
+    pub actor_id: i32,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionStatusEventEntity {
+    pub fn to_model(&self, actor: &BrawlerProfileEntity) -> MissionStatusEventModel {
+        MissionStatusEventModel {
+            id: self.id,
+            from_status: self.from_status,
+            to_status: self.to_status,
+            actor_id: self.actor_id,
+            actor_username: actor.username.clone(),
+            actor_display_name: actor.display_name.clone(),
+            reason: self.reason.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_status_events)]
+pub struct NewMissionStatusEvent {
+    pub mission_id: i32,
+    pub from_status: MissionStatuses,
+    pub to_status: MissionStatuses,
+    pub actor_id: i32,
+    pub reason: Option<String>,
+}
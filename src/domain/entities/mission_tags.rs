@@ -0,0 +1,10 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::mission_tags;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_tags)]
+pub struct NewMissionTag {
+    pub mission_id: i32,
+    pub tag_id: i32,
+}
@@ -1,4 +1,4 @@
-use crate::{domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions};
+use crate::{domain::{entities::brawlers::BrawlerProfileEntity, value_objects::{mission_model::MissionModel, mission_statuses::{MissionStatuses, TransitionGuards}, venue_model::VenueModel}}, infrastructure::database::schema::missions};
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
@@ -9,10 +9,20 @@ pub struct MissionEntity {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub created_at: NaiveDateTime,
-    pub updated_at: NaiveDateTime
+    pub updated_at: NaiveDateTime,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub timezone: String,
+    pub venue_name: Option<String>,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub max_crew: i32,
+    pub min_crew: i32,
+    pub join_policy: String,
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -20,8 +30,18 @@ pub struct MissionEntity {
 pub struct NewMission {
     pub name: String,
     pub description: String,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub timezone: String,
+    pub venue_name: Option<String>,
+    pub venue_address: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub max_crew: i32,
+    pub min_crew: i32,
+    pub join_policy: String,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
@@ -29,22 +49,70 @@ pub struct NewMission {
 pub struct UpdateMission {
     pub name: Option<String>,
     pub description: Option<String>,
-    pub status: Option<String>,
-    pub chief_id: i32,
+    pub starts_at: Option<NaiveDateTime>,
+    pub ends_at: Option<NaiveDateTime>,
+    pub timezone: Option<String>,
+    pub venue_name: Option<String>,
+    pub venue_address: Option<Option<String>>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub max_crew: Option<i32>,
+    pub min_crew: Option<i32>,
+    pub join_policy: Option<String>,
 }
 
 
 impl MissionEntity {
-    pub fn to_model(&self, crew_count: i64) -> MissionModel {
+    pub fn to_model(&self, chief: &BrawlerProfileEntity, crew_count: i64) -> MissionModel {
         MissionModel {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
+            status: self.status,
             chief_id: self.chief_id,
+            chief_display_name: chief.display_name.clone(),
+            chief_avatar_url: chief.avatar_url.clone(),
             crew_count,
+            starts_at: self.starts_at.and_utc(),
+            ends_at: self.ends_at.and_utc(),
+            timezone: self.timezone.clone(),
+            duration_minutes: (self.ends_at - self.starts_at).num_minutes(),
+            venue: self.venue(),
+            max_crew: self.max_crew,
+            min_crew: self.min_crew,
+            join_policy: self.join_policy.clone(),
+            distance_km: None,
+            snippet: None,
+            tags: Vec::new(),
             created_at: self.created_at,
             updated_at: self.updated_at,
         }   
     }
-}
\ No newline at end of file
+
+    pub fn has_room_for_more_crew(&self, crew_count: u32) -> bool {
+        i64::from(crew_count) < i64::from(self.max_crew)
+    }
+
+    pub fn has_enough_crew(&self, crew_count: u32) -> bool {
+        let crew_count = i64::from(crew_count);
+        crew_count >= i64::from(self.min_crew) && crew_count <= i64::from(self.max_crew)
+    }
+
+    pub fn transition_guards(&self, crew_count: u32) -> TransitionGuards {
+        TransitionGuards {
+            has_enough_crew: self.has_enough_crew(crew_count),
+        }
+    }
+
+    pub fn venue(&self) -> Option<VenueModel> {
+        match (&self.venue_name, self.latitude, self.longitude) {
+            (Some(name), Some(latitude), Some(longitude)) => Some(VenueModel {
+                name: name.clone(),
+                address: self.venue_address.clone(),
+                latitude,
+                longitude,
+            }),
+            _ => None,
+        }
+    }
+}
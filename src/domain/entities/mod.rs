@@ -1,3 +1,13 @@
 pub mod brawlers;
 pub mod crew_memberships;
-pub mod missions;
\ No newline at end of file
+pub mod join_requests;
+pub mod mission_bans;
+pub mod mission_invites;
+pub mod mission_status_events;
+pub mod mission_tags;
+pub mod missions;
+pub mod password_reset_tokens;
+pub mod recovery_codes;
+pub mod sessions;
+pub mod tags;
+pub mod waitlist_entries;
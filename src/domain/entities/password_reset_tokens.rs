@@ -0,0 +1,12 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::password_reset_tokens;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = password_reset_tokens)]
+pub struct NewPasswordResetToken {
+    pub brawler_id: i32,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+}
@@ -0,0 +1,22 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{domain::entities::brawlers::BrawlerEntity, infrastructure::database::schema::recovery_codes};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
+#[diesel(table_name = recovery_codes)]
+pub struct RecoveryCodeEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub code_hash: String,
+    pub created_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = recovery_codes)]
+pub struct NewRecoveryCode {
+    pub brawler_id: i32,
+    pub code_hash: String,
+}
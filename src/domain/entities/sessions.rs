@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{domain::entities::brawlers::BrawlerEntity, infrastructure::database::schema::sessions};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
+#[diesel(primary_key(jti))]
+#[diesel(table_name = sessions)]
+pub struct SessionEntity {
+    pub jti: String,
+    pub brawler_id: i32,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = sessions)]
+pub struct NewSession {
+    pub jti: String,
+    pub brawler_id: i32,
+    pub expires_at: NaiveDateTime,
+}
@@ -0,0 +1,9 @@
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::tags;
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = tags)]
+pub struct NewTag {
+    pub name: String,
+}
@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::entities::{brawlers::BrawlerEntity, missions::MissionEntity},
+    infrastructure::database::schema::waitlist_entries,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable, Associations)]
+#[diesel(belongs_to(BrawlerEntity, foreign_key = brawler_id))]
+#[diesel(belongs_to(MissionEntity, foreign_key = mission_id))]
+#[diesel(table_name = waitlist_entries)]
+pub struct WaitlistEntryEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub joined_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = waitlist_entries)]
+pub struct NewWaitlistEntry {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+}
@@ -1,3 +1,4 @@
+pub mod app_error;
 pub mod entities;
 pub mod repositories;
 pub mod value_objects;
\ No newline at end of file
@@ -1,14 +1,28 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use mockall::automock;
-use crate::domain::entities::{brawlers::{BrawlerEntity, RegisterBrawlerEntity}, missions::MissionEntity};
+use crate::domain::entities::{brawlers::{BrawlerEntity, BrawlerProfileEntity, RegisterBrawlerEntity}, missions::MissionEntity};
 
 #[async_trait]
 #[automock]
 pub trait BrawlerRepository {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
     async fn find_by_username(&self, username: String) -> Result<BrawlerEntity>;
-    async fn update_avatar(&self, id: i32, avatar_url: String) -> Result<String>;
-    async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
-    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionEntity>>;
+    async fn find_by_id(&self, id: i32) -> Result<BrawlerEntity>;
+    async fn update_email(&self, id: i32, email: Option<String>) -> Result<()>;
+    async fn update_password(&self, id: i32, hashed_password: String) -> Result<()>;
+    async fn update_totp(&self, id: i32, totp_secret: Option<String>, totp_enabled: bool) -> Result<()>;
+    /// Stores `step` as the last accepted TOTP step unless an equal or later one is
+    /// already stored. Returns false when the code was already used.
+    async fn record_totp_step(&self, id: i32, step: i64) -> Result<bool>;
+    /// Counts a wrong second-factor code. The `max_attempts`-th one in a row locks
+    /// two-factor checks until `locked_until` and starts the count over.
+    async fn record_second_factor_failure(&self, id: i32, max_attempts: i32, locked_until: NaiveDateTime) -> Result<()>;
+    async fn reset_second_factor_failures(&self, id: i32) -> Result<()>;
+    async fn update_avatar(&self, id: i32, avatar_url: String, avatar_public_id: Option<String>) -> Result<()>;
+    /// Missions the brawler leads or crews, with their chief and crew count.
+    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<(MissionEntity, BrawlerProfileEntity, i64)>>;
+    /// `(mission_id, tag)` pairs for the given missions, tags in name order.
+    async fn mission_tags(&self, mission_ids: &[i32]) -> Result<Vec<(i32, String)>>;
 }
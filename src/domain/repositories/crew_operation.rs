@@ -1,29 +1,163 @@
 use anyhow::Result;
-use async_trait::async_trait;
-use diesel::{
-    PgConnection,
-    r2d2::{ConnectionManager, PooledConnection},
-};
 use mockall::automock;
 
-use crate::domain::entities::crew_memberships::CrewMembershipEntity;
+use crate::domain::entities::{
+    brawlers::BrawlerProfileEntity,
+    crew_memberships::NewCrewMembership,
+    join_requests::{JoinRequestEntity, NewJoinRequest},
+    mission_bans::NewMissionBan,
+    mission_invites::{MissionInviteEntity, NewMissionInvite},
+    missions::MissionEntity,
+    waitlist_entries::{NewWaitlistEntry, WaitlistEntryEntity},
+};
 
-#[async_trait]
-#[automock]
+/// Every method runs on the connection of a `TransactionProvider` transaction, so a
+/// capacity check and the insert that depends on it cannot interleave with another join.
+#[automock(type Connection = ();)]
 pub trait CrewOperationRepository {
-    async fn join(&self, crew_memberships: CrewMembershipEntity) -> Result<()>;
-    async fn leave(&self, crew_memberships: CrewMembershipEntity) -> Result<()>;
+    type Connection;
 
-    //testing method
-    fn for_insert_transaction_test(
+    /// Loads the mission with `SELECT ... FOR UPDATE`, holding its row lock until the
+    /// transaction ends.
+    fn lock_mission(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<MissionEntity>;
+    fn crew_counting(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<u32>;
+    fn is_crew_member(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<bool>;
+    fn join(
+        &self,
+        conn: &mut Self::Connection,
+        crew_memberships: NewCrewMembership,
+    ) -> Result<()>;
+    /// Returns how many memberships were removed, so callers can tell "not in the crew".
+    fn leave(
+        &self,
+        conn: &mut Self::Connection,
+        crew_memberships: NewCrewMembership,
+    ) -> Result<usize>;
+    fn set_crew_role(
         &self,
-        conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
-        crew_memberships: CrewMembershipEntity,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+        role: String,
     ) -> Result<()>;
-    fn for_delete_transaction_test(
+    fn set_chief(
         &self,
-        conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
-        crew_memberships: CrewMembershipEntity,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        chief_id: i32,
     ) -> Result<()>;
 
+    fn join_waitlist(
+        &self,
+        conn: &mut Self::Connection,
+        new_waitlist_entry: NewWaitlistEntry,
+    ) -> Result<()>;
+    /// Returns how many entries were removed, so callers can tell "not on the list".
+    fn leave_waitlist(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<usize>;
+    fn waitlist_head(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<Option<WaitlistEntryEntity>>;
+    /// 1-based place in line, or `None` when the brawler is not waiting.
+    fn waitlist_position(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Option<u32>>;
+    fn waitlist_size(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<u32>;
+    fn create_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        new_join_request: NewJoinRequest,
+    ) -> Result<()>;
+    fn has_pending_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<bool>;
+    /// Only finds requests that are still waiting for the chief.
+    fn pending_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        request_id: i32,
+    ) -> Result<Option<JoinRequestEntity>>;
+    /// Oldest first, with the profile of whoever asked.
+    fn pending_join_requests(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<Vec<(JoinRequestEntity, BrawlerProfileEntity)>>;
+    fn decide_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        request_id: i32,
+        status: String,
+    ) -> Result<()>;
+    fn create_invite(
+        &self,
+        conn: &mut Self::Connection,
+        new_mission_invite: NewMissionInvite,
+    ) -> Result<MissionInviteEntity>;
+    /// Finds an invite that is not revoked, not expired and has uses left.
+    fn usable_invite(
+        &self,
+        conn: &mut Self::Connection,
+        token_hash: String,
+    ) -> Result<Option<MissionInviteEntity>>;
+    /// Takes one use of the invite, returning `false` if it stopped being usable meanwhile.
+    fn consume_invite(
+        &self,
+        conn: &mut Self::Connection,
+        invite_id: i32,
+    ) -> Result<bool>;
+    fn active_invites(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<Vec<MissionInviteEntity>>;
+    /// Returns how many invites were revoked, so callers can tell "no such invite".
+    fn revoke_invite(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        invite_id: i32,
+    ) -> Result<usize>;
+    fn is_banned(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<bool>;
+    /// Banning someone who is already banned is a no-op.
+    fn ban(
+        &self,
+        conn: &mut Self::Connection,
+        new_mission_ban: NewMissionBan,
+    ) -> Result<()>;
 }
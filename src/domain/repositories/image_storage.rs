@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage};
+
+#[async_trait]
+#[automock]
+pub trait ImageStorage {
+    /// Stores the image under `folder` and returns its public URL together with
+    /// the id needed to delete it later.
+    async fn upload(&self, base64_image: Base64Image, folder: &str) -> Result<UploadedImage>;
+    async fn delete(&self, public_id: &str) -> Result<()>;
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::value_objects::mail_message::MailMessage;
+
+#[async_trait]
+#[automock]
+pub trait Mailer {
+    async fn send(&self, message: MailMessage) -> Result<()>;
+}
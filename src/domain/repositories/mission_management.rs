@@ -6,7 +6,15 @@ use crate::domain::entities::missions::{NewMission, UpdateMission};
 #[async_trait]
 #[automock]
 pub trait MissionManagementRepository {
-    async fn add(&self, add_mission_entity: NewMission) -> Result<i32>;
-    async fn edit(&self, mission_id: i32, edit_mission_entity: UpdateMission) -> Result<i32>;
+    /// Inserts the mission together with its (already normalized) tags.
+    async fn add(&self, add_mission_entity: NewMission, tags: Vec<String>) -> Result<i32>;
+    /// `tags`, when given, replace the mission's current tags.
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_entity: UpdateMission,
+        tags: Option<Vec<String>>,
+    ) -> Result<i32>;
     async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()>;
 }
@@ -2,10 +2,21 @@ use anyhow::Result;
 use async_trait::async_trait;
 use mockall::automock;
 
+use crate::domain::value_objects::mission_statuses::MissionStatuses;
+
+/// Callers are expected to have checked that the brawler may lead the mission.
 #[async_trait]
 #[automock]
 pub trait MissionOperationRepository {
-    async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32>;
+    /// Moves the mission to `to_status` and records the transition in its history.
+    /// The transition is checked again with the mission locked, so a concurrent status or
+    /// crew change fails it with `AppError::Conflict`.
+    async fn change_status(
+        &self,
+        mission_id: i32,
+        to_status: MissionStatuses,
+        actor_id: i32,
+        reason: Option<String>,
+    ) -> Result<i32>;
+    async fn set_crew_role(&self, mission_id: i32, brawler_id: i32, role: String) -> Result<()>;
 }
@@ -2,12 +2,43 @@ use anyhow::Result;
 use async_trait::async_trait;
 use mockall::automock;
 use crate::domain::value_objects::mission_filter::MissionFilter;
-use crate::domain::entities::missions::MissionEntity;
+use crate::domain::entities::{brawlers::BrawlerProfileEntity, crew_memberships::CrewMembershipEntity, mission_status_events::MissionStatusEventEntity, missions::MissionEntity};
 
 #[async_trait]
 #[automock]
 pub trait MissionViewingRepository {
     async fn view_detail(&self, mission_id: i32) -> Result<MissionEntity>;
-    async fn get(&self, filter: &MissionFilter) -> Result<Vec<MissionEntity>>;
+    /// One page of matching missions, in the filter's sort order, with their chief,
+    /// crew count and, when searching with `q`, a highlighted snippet.
+    async fn get(
+        &self,
+        filter: &MissionFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(MissionEntity, BrawlerProfileEntity, i64, Option<String>)>>;
+    /// How many missions match the filter across all pages.
+    async fn count(&self, filter: &MissionFilter) -> Result<i64>;
+    async fn chief_profile(&self, chief_id: i32) -> Result<BrawlerProfileEntity>;
     async fn crew_counting(&self, mission_id: i32) -> Result<u32>;
+    /// The brawler's crew role in the mission, or `None` if they are not in its crew.
+    async fn crew_role(&self, mission_id: i32, brawler_id: i32) -> Result<Option<String>>;
+    async fn is_waitlisted(&self, mission_id: i32, brawler_id: i32) -> Result<bool>;
+    /// Whether `token_hash` belongs to an invite of the mission that can still be redeemed.
+    async fn has_usable_invite(&self, mission_id: i32, token_hash: String) -> Result<bool>;
+    /// One page of the crew with their profiles, earliest joiners first.
+    async fn crew_roster(
+        &self,
+        mission_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(CrewMembershipEntity, BrawlerProfileEntity)>>;
+    /// Every status transition of the mission with the brawler who made it, oldest first.
+    async fn status_history(
+        &self,
+        mission_id: i32,
+    ) -> Result<Vec<(MissionStatusEventEntity, BrawlerProfileEntity)>>;
+    /// `(mission_id, tag)` pairs for the given missions, tags in name order.
+    async fn mission_tags(&self, mission_ids: &[i32]) -> Result<Vec<(i32, String)>>;
+    /// The most used tags on browsable missions, with how many missions carry each.
+    async fn popular_tags(&self, limit: i64) -> Result<Vec<(String, i64)>>;
 }
@@ -1,6 +1,11 @@
 pub mod brawlers;
 pub mod crew_operation;
+pub mod image_storage;
+pub mod mailer;
 pub mod mission_management;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod password_reset;
+pub mod recovery_codes;
+pub mod sessions;
 pub mod transaction_provider;
\ No newline at end of file
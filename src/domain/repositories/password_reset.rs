@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::password_reset_tokens::NewPasswordResetToken;
+
+#[async_trait]
+#[automock]
+pub trait PasswordResetRepository {
+    async fn create(&self, new_password_reset_token: NewPasswordResetToken) -> Result<()>;
+    /// In one transaction: marks an unused, unexpired token as used, sets its brawler's
+    /// password and revokes their sessions. Returns the brawler, or `None` when the token
+    /// is unknown, used or expired.
+    async fn reset_password(&self, token_hash: String, hashed_password: String) -> Result<Option<i32>>;
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::recovery_codes::{NewRecoveryCode, RecoveryCodeEntity};
+
+#[async_trait]
+#[automock]
+pub trait RecoveryCodeRepository {
+    /// Drops every existing code of the brawler and stores the new ones.
+    async fn replace_all(&self, brawler_id: i32, recovery_codes: Vec<NewRecoveryCode>) -> Result<()>;
+    async fn find_unused(&self, brawler_id: i32) -> Result<Vec<RecoveryCodeEntity>>;
+    async fn mark_used(&self, recovery_code_id: i32) -> Result<()>;
+    async fn delete_all(&self, brawler_id: i32) -> Result<()>;
+}
@@ -0,0 +1,16 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::domain::entities::sessions::NewSession;
+
+#[async_trait]
+#[automock]
+pub trait SessionRepository {
+    async fn create(&self, new_session: NewSession) -> Result<()>;
+    async fn is_active(&self, jti: String) -> Result<bool>;
+    /// Revokes the session if it is still active, in one conditional update.
+    /// Returns how many sessions that revoked, so 0 means it was already revoked or expired.
+    async fn revoke(&self, jti: String) -> Result<usize>;
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()>;
+}
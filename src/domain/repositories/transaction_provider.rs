@@ -1,13 +1,11 @@
-use diesel::{
-    PgConnection,
-    r2d2::{ConnectionManager, PooledConnection},
-};
-
-#[mockall::automock]
+/// Runs a unit of work inside one database transaction. `Connection` is what the
+/// repositories taking part in it are handed, so tests can pass one that needs no database.
 pub trait TransactionProvider {
+    type Connection;
+
     fn transaction<T, E, F>(&self, f: F) -> Result<T, E>
     where
-        F: FnOnce(&mut PooledConnection<ConnectionManager<PgConnection>>) -> Result<T, E> + 'static,
+        F: FnOnce(&mut Self::Connection) -> Result<T, E> + 'static,
         T: 'static,
         E: From<anyhow::Error> + From<diesel::result::Error> + 'static;
-}
\ No newline at end of file
+}
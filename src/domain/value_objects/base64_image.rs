@@ -1,13 +1,19 @@
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose};
 
+/// Image types accepted for upload.
+const ALLOWED_MIME_TYPES: [&str; 2] = ["image/png", "image/jpeg"];
 
-
+/// A base64 `data:` URL whose type was sniffed from the decoded bytes, not taken
+/// from the client.
 #[derive(Debug, Clone)]
-pub struct Base64Image(String);
+pub struct Base64Image {
+    data_url: String,
+    extension: &'static str,
+}
 
 impl Base64Image {
-    pub fn new(data: &String) -> Result<Self> {
+    pub fn new(data: &str) -> Result<Self> {
         if data.is_empty() {
             return Err(anyhow::anyhow!("Base64Image is empty !!"));
         }
@@ -17,18 +23,30 @@ impl Base64Image {
             Err(_) => return Err(anyhow::anyhow!("Invalid base64 image data.")),
         };
         let file_type = match infer::get(&bytes) {
-            Some(t) => if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" {
-                t.mime_type()
-            } else {
-                return Err(anyhow::anyhow!("Invalid base64 image data."));
-            },
-            _ => return Err(anyhow::anyhow!("Invalid base64 image data.")),
+            Some(t) if ALLOWED_MIME_TYPES.contains(&t.mime_type()) => t,
+            _ => return Err(anyhow::anyhow!("Unsupported image type, expected PNG or JPEG.")),
         };
 
-        Ok(Self(format!("data:{};base64,{}", file_type, &data)))
-    }   
+        Ok(Self {
+            data_url: format!("data:{};base64,{}", file_type.mime_type(), &data),
+            extension: file_type.extension(),
+        })
+    }
+
+    /// File extension matching the detected image type.
+    pub fn extension(&self) -> &str {
+        self.extension
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let (_, data) = self
+            .data_url
+            .split_once(";base64,")
+            .ok_or_else(|| anyhow::anyhow!("Invalid base64 image data."))?;
+        Ok(general_purpose::STANDARD.decode(data)?)
+    }
+
     pub fn into_inner(self) -> String {
-        self.0
+        self.data_url
     }
 }
-
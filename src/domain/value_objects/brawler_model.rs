@@ -7,6 +7,8 @@ pub struct RegisterBrawlerModel {
     pub username: String,
     pub password: String,
     pub display_name: String,
+    #[serde(default)]
+    pub email: Option<String>,
 }
 
 impl RegisterBrawlerModel {
@@ -14,6 +16,15 @@ impl RegisterBrawlerModel {
         RegisterBrawlerEntity {
             username: self.username.clone(),
             password: self.password.clone(),
+            email: self.email.clone(),
+            display_name: self.display_name.trim().to_string(),
         }
     }
 }
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateEmailModel {
+    /// Leaving it out or blank removes the address, which turns off password reset mails.
+    #[serde(default)]
+    pub email: Option<String>,
+}
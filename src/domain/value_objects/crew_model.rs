@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// A crew member's standing in a mission. A chief who is also in the crew counts as a
+/// plain `Member`.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum CrewRoles {
+    #[default]
+    Member,
+    /// May run the mission's status transitions alongside the chief.
+    CoChief,
+}
+
+impl Display for CrewRoles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrewRoles::Member => write!(f, "Member"),
+            CrewRoles::CoChief => write!(f, "CoChief"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrewRoleModel {
+    pub role: CrewRoles,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct KickCrewMemberQuery {
+    /// Also keep the brawler from rejoining this mission.
+    #[serde(default)]
+    pub ban: bool,
+}
+
+/// One row of a mission's crew roster.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrewMemberModel {
+    pub brawler_id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub joined_at: NaiveDateTime,
+    pub role: String,
+}
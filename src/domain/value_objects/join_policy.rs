@@ -0,0 +1,24 @@
+use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+
+/// Who may take a seat in a mission's crew.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum JoinPolicy {
+    /// Anyone can join straight away.
+    #[default]
+    Open,
+    /// Joining files a request the chief has to approve.
+    ApprovalRequired,
+    /// Only brawlers holding an invite can join.
+    InviteOnly,
+}
+
+impl Display for JoinPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinPolicy::Open => write!(f, "Open"),
+            JoinPolicy::ApprovalRequired => write!(f, "ApprovalRequired"),
+            JoinPolicy::InviteOnly => write!(f, "InviteOnly"),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::fmt::Display;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum JoinRequestStatuses {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl Display for JoinRequestStatuses {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinRequestStatuses::Pending => write!(f, "Pending"),
+            JoinRequestStatuses::Approved => write!(f, "Approved"),
+            JoinRequestStatuses::Rejected => write!(f, "Rejected"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JoinRequestModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub status: String,
+    pub created_at: NaiveDateTime,
+}
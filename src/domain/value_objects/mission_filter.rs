@@ -1,9 +1,161 @@
+use chrono::{DateTime, Datelike, Days, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::domain::{
+    app_error::{AppError, AppResult},
+    value_objects::{
+        mission_statuses::MissionStatuses, pagination::PageQuery, tag_model::parse_tag_list,
+        venue_model::validate_coordinates,
+    },
+};
+
+pub const MAX_RADIUS_KM: f64 = 500.0;
+pub const MAX_SEARCH_LENGTH: usize = 200;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionPeriod {
+    /// Starts from now on.
+    Upcoming,
+    /// Starts from now until the end of the current week (Monday based, UTC).
+    ThisWeek,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionSort {
+    /// Newest first.
+    Created,
+    /// Soonest `starts_at` first.
+    StartsSoon,
+    /// Largest crew first.
+    MostCrew,
+    /// Alphabetical by name.
+    Name,
+    /// Closest first. Needs `lat`, `lng` and `radius_km`.
+    Distance,
+    /// Best full-text match first. Needs `q`.
+    Relevance,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MissionFilter {
+    /// Full-text search over name and description.
+    pub q: Option<String>,
     pub name: Option<String>,
+    /// Comma-separated tags; missions carrying any of them match.
+    pub any_tags: Option<String>,
+    /// Comma-separated tags; only missions carrying all of them match.
+    pub all_tags: Option<String>,
     pub status: Option<MissionStatuses>,
+    pub period: Option<MissionPeriod>,
+    pub starts_from: Option<DateTime<Utc>>,
+    pub starts_to: Option<DateTime<Utc>>,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    pub radius_km: Option<f64>,
+    pub sort: Option<MissionSort>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl MissionFilter {
+    /// The `((lat, lng), radius_km)` to search around, if any.
+    /// The three parameters only make sense together.
+    pub fn near(&self) -> AppResult<Option<((f64, f64), f64)>> {
+        match (self.lat, self.lng, self.radius_km) {
+            (None, None, None) => Ok(None),
+            (Some(lat), Some(lng), Some(radius_km)) => {
+                validate_coordinates(lat, lng)?;
+                if !(radius_km > 0.0 && radius_km <= MAX_RADIUS_KM) {
+                    return Err(AppError::Validation(format!(
+                        "radius_km must be greater than 0 and at most {}",
+                        MAX_RADIUS_KM
+                    )));
+                }
+                Ok(Some(((lat, lng), radius_km)))
+            }
+            _ => Err(AppError::Validation(
+                "lat, lng and radius_km must be given together".to_string(),
+            )),
+        }
+    }
+
+    /// The trimmed `q`, or `None` when it is missing or blank.
+    pub fn search_text(&self) -> AppResult<Option<&str>> {
+        let Some(q) = self.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) else {
+            return Ok(None);
+        };
+        if q.chars().count() > MAX_SEARCH_LENGTH {
+            return Err(AppError::Validation(format!(
+                "q must be at most {} characters",
+                MAX_SEARCH_LENGTH
+            )));
+        }
+        Ok(Some(q))
+    }
+
+    /// The normalized `(any_tags, all_tags)` lists, empty when not given.
+    pub fn tag_filters(&self) -> AppResult<(Vec<String>, Vec<String>)> {
+        Ok((
+            parse_tag_list(self.any_tags.as_deref())?,
+            parse_tag_list(self.all_tags.as_deref())?,
+        ))
+    }
+
+    /// The requested sort, defaulting to best match first for text searches,
+    /// closest first for radius searches and newest first otherwise.
+    pub fn sort_by(&self) -> AppResult<MissionSort> {
+        let searching = self.search_text()?.is_some();
+        let near = self.near()?.is_some();
+        match self.sort {
+            Some(MissionSort::Distance) if !near => Err(AppError::Validation(
+                "sort=distance needs lat, lng and radius_km".to_string(),
+            )),
+            Some(MissionSort::Relevance) if !searching => Err(AppError::Validation(
+                "sort=relevance needs q".to_string(),
+            )),
+            Some(sort) => Ok(sort),
+            None if searching => Ok(MissionSort::Relevance),
+            None if near => Ok(MissionSort::Distance),
+            None => Ok(MissionSort::Created),
+        }
+    }
+
+    pub fn page_query(&self) -> PageQuery {
+        PageQuery {
+            page: self.page,
+            limit: self.limit,
+        }
+    }
+
+    /// Resolves `period` and the explicit bounds into a single `[from, to)` window
+    /// on `starts_at`. When both are given the narrower bound wins.
+    pub fn starts_between(
+        &self,
+        now: DateTime<Utc>,
+    ) -> (Option<DateTime<Utc>>, Option<DateTime<Utc>>) {
+        let (period_from, period_to) = match self.period {
+            Some(MissionPeriod::Upcoming) => (Some(now), None),
+            Some(MissionPeriod::ThisWeek) => {
+                let days_left = u64::from(7 - now.weekday().num_days_from_monday());
+                let next_monday = (now.date_naive() + Days::new(days_left))
+                    .and_hms_opt(0, 0, 0)
+                    .map(|midnight| midnight.and_utc());
+                (Some(now), next_monday)
+            }
+            None => (None, None),
+        };
+
+        let from = match (period_from, self.starts_from) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        let to = match (period_to, self.starts_to) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        (from, to)
+    }
 }
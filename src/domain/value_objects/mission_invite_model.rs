@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NewMissionInviteModel {
+    /// Leave empty for an invite that never expires.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Leave empty for an invite that can be used any number of times.
+    #[serde(default)]
+    pub max_uses: Option<i32>,
+}
+
+/// An invite as the chief sees it; the token itself is only shown once, on creation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionInviteModel {
+    pub id: i32,
+    pub mission_id: i32,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_uses: Option<i32>,
+    pub uses: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CreatedMissionInviteModel {
+    #[serde(flatten)]
+    pub invite: MissionInviteModel,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RedeemMissionInviteModel {
+    pub token: String,
+}
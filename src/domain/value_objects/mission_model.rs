@@ -1,9 +1,11 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use crate::{domain::entities::{
-    missions::NewMission, missions::UpdateMission
+    crew_memberships::DEFAULT_MAX_CREW, missions::NewMission, missions::UpdateMission
 },
-    domain::value_objects::mission_statuses::MissionStatuses,
+    domain::value_objects::{
+        join_policy::JoinPolicy, mission_statuses::MissionStatuses, venue_model::VenueModel,
+    },
 
 };
 
@@ -13,9 +15,24 @@ pub struct MissionModel {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
+    pub chief_display_name: String,
+    pub chief_avatar_url: Option<String>,
     pub crew_count: i64,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub timezone: String,
+    pub duration_minutes: i64,
+    pub venue: Option<VenueModel>,
+    pub max_crew: i32,
+    pub min_crew: i32,
+    pub join_policy: String,
+    /// Only set when searching around a point.
+    pub distance_km: Option<f64>,
+    /// Only set when searching with `q`. HTML-escaped, with matching terms wrapped in `<b>`.
+    pub snippet: Option<String>,
+    pub tags: Vec<String>,
     pub updated_at: NaiveDateTime,
     pub created_at: NaiveDateTime,
 }
@@ -26,6 +43,28 @@ pub struct NewMissionModel {
     pub name: String,
     #[serde(default)]
     pub description: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// IANA name such as `Asia/Bangkok`, used by clients to show local times.
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+    #[serde(default)]
+    pub venue: Option<VenueModel>,
+    #[serde(default)]
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub min_crew: Option<i32>,
+    #[serde(default)]
+    pub join_policy: Option<JoinPolicy>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Keeps the mission hidden and closed to crew until the chief publishes it.
+    #[serde(default)]
+    pub draft: bool,
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
 }
 
 impl NewMissionModel {
@@ -33,28 +72,66 @@ impl NewMissionModel {
         NewMission {
             name: self.name.clone(),
             description: self.description.clone().unwrap_or_default(),
-            status: MissionStatuses::Open.to_string(),
+            status: if self.draft {
+                MissionStatuses::Draft
+            } else {
+                MissionStatuses::Open
+            },
             chief_id,
+            starts_at: self.starts_at.naive_utc(),
+            ends_at: self.ends_at.naive_utc(),
+            timezone: self.timezone.clone(),
+            venue_name: self.venue.as_ref().map(|venue| venue.name.clone()),
+            venue_address: self.venue.as_ref().and_then(|venue| venue.address.clone()),
+            latitude: self.venue.as_ref().map(|venue| venue.latitude),
+            longitude: self.venue.as_ref().map(|venue| venue.longitude),
+            max_crew: self.max_crew.unwrap_or(DEFAULT_MAX_CREW),
+            min_crew: self.min_crew.unwrap_or(1),
+            join_policy: self.join_policy.clone().unwrap_or_default().to_string(),
         }
     }
 }
-#[derive(Debug, Clone, Serialize, Deserialize,PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize,PartialEq)]
 pub struct UpdateMissionModel {
     #[serde(default)]
     pub name: Option<String>,
     #[serde(default)]
     pub description: Option<String>,
     #[serde(default)]
-    pub status: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub ends_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub venue: Option<VenueModel>,
+    #[serde(default)]
+    pub max_crew: Option<i32>,
+    #[serde(default)]
+    pub min_crew: Option<i32>,
+    #[serde(default)]
+    pub join_policy: Option<JoinPolicy>,
+    /// Replaces every tag on the mission. An empty list clears them.
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
 }
 
 impl UpdateMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> UpdateMission {
+    pub fn to_entity(&self) -> UpdateMission {
         UpdateMission {
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
-            chief_id,
+            starts_at: self.starts_at.map(|starts_at| starts_at.naive_utc()),
+            ends_at: self.ends_at.map(|ends_at| ends_at.naive_utc()),
+            timezone: self.timezone.clone(),
+            venue_name: self.venue.as_ref().map(|venue| venue.name.clone()),
+            // A new venue replaces the old one entirely, including a missing address.
+            venue_address: self.venue.as_ref().map(|venue| venue.address.clone()),
+            latitude: self.venue.as_ref().map(|venue| venue.latitude),
+            longitude: self.venue.as_ref().map(|venue| venue.longitude),
+            max_crew: self.max_crew,
+            min_crew: self.min_crew,
+            join_policy: self.join_policy.as_ref().map(JoinPolicy::to_string),
         }
     }
 }
\ No newline at end of file
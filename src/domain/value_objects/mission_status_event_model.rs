@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    app_error::{AppError, AppResult},
+    value_objects::mission_statuses::MissionStatuses,
+};
+
+pub const MAX_STATUS_REASON_LENGTH: usize = 500;
+
+/// Optional body of a status transition request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct MissionStatusChangeModel {
+    pub reason: Option<String>,
+}
+
+impl MissionStatusChangeModel {
+    /// The trimmed reason, or `None` when it is missing or blank.
+    pub fn reason(&self) -> AppResult<Option<String>> {
+        let Some(reason) = self.reason.as_deref().map(str::trim).filter(|r| !r.is_empty()) else {
+            return Ok(None);
+        };
+        if reason.chars().count() > MAX_STATUS_REASON_LENGTH {
+            return Err(AppError::Validation(format!(
+                "reason must be at most {} characters",
+                MAX_STATUS_REASON_LENGTH
+            )));
+        }
+        Ok(Some(reason.to_string()))
+    }
+}
+
+/// One entry of a mission's status history.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionStatusEventModel {
+    pub id: i32,
+    pub from_status: MissionStatuses,
+    pub to_status: MissionStatuses,
+    pub actor_id: i32,
+    pub actor_username: String,
+    pub actor_display_name: String,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+}
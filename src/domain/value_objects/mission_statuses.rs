@@ -1,21 +1,133 @@
-use std::fmt::Display;
+use std::{fmt::Display, io::Write};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
 use serde::{Deserialize, Serialize};
-#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+
+use crate::{
+    domain::app_error::{AppError, AppResult},
+    infrastructure::database::schema::sql_types::MissionStatus,
+};
+
+/// Where a mission is in its lifecycle. Stored as the `mission_status` Postgres enum,
+/// and only ever changed along the edges of [`MissionStatuses::allowed_transitions`].
+#[derive(
+    Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = MissionStatus)]
 pub enum MissionStatuses {
+    /// Still being prepared by its chief: hidden from listings and closed to crew.
+    Draft,
     #[default]
     Open,
     InProgress,
     Completed,
+    /// Can be retried by starting it again.
     Failed,
+    /// Called off by its chief. Nothing happens to a cancelled mission afterwards.
+    Cancelled,
+}
+
+/// Facts about a mission that some transitions depend on.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TransitionGuards {
+    /// Crew size is within the mission's `min_crew..=max_crew`.
+    pub has_enough_crew: bool,
+}
+
+impl MissionStatuses {
+    /// Statuses in which the chief may still edit or delete the mission.
+    pub const EDITABLE: [MissionStatuses; 2] = [MissionStatuses::Draft, MissionStatuses::Open];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MissionStatuses::Draft => "Draft",
+            MissionStatuses::Open => "Open",
+            MissionStatuses::InProgress => "InProgress",
+            MissionStatuses::Completed => "Completed",
+            MissionStatuses::Failed => "Failed",
+            MissionStatuses::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn allowed_transitions(&self) -> &'static [MissionStatuses] {
+        match self {
+            MissionStatuses::Draft => &[MissionStatuses::Open, MissionStatuses::Cancelled],
+            MissionStatuses::Open => &[MissionStatuses::InProgress, MissionStatuses::Cancelled],
+            MissionStatuses::InProgress => &[MissionStatuses::Completed, MissionStatuses::Failed],
+            MissionStatuses::Failed => &[MissionStatuses::InProgress, MissionStatuses::Cancelled],
+            MissionStatuses::Completed | MissionStatuses::Cancelled => &[],
+        }
+    }
+
+    pub fn can_transition_to(&self, next: MissionStatuses) -> bool {
+        self.allowed_transitions().contains(&next)
+    }
+
+    /// Checks `next` against the transition table and the guards it needs, returning it
+    /// when the mission may move there.
+    pub fn transition_to(
+        &self,
+        next: MissionStatuses,
+        guards: TransitionGuards,
+    ) -> AppResult<MissionStatuses> {
+        if !self.can_transition_to(next) {
+            return Err(AppError::Conflict(format!(
+                "A mission cannot go from {} to {}",
+                self, next
+            )));
+        }
+
+        if next == MissionStatuses::InProgress && !guards.has_enough_crew {
+            return Err(AppError::Conflict(
+                "Mission needs between its minimum and maximum crew to start".to_string(),
+            ));
+        }
+
+        Ok(next)
+    }
+
+    /// Whether brawlers may join, leave, request, be invited or be kicked.
+    pub fn accepts_crew_changes(&self) -> bool {
+        matches!(self, MissionStatuses::Open | MissionStatuses::Failed)
+    }
+
+    pub fn is_editable(&self) -> bool {
+        Self::EDITABLE.contains(self)
+    }
 }
 
 impl Display for MissionStatuses {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            MissionStatuses::Open => write!(f, "Open"),
-            MissionStatuses::InProgress => write!(f, "InProgress"),
-            MissionStatuses::Completed => write!(f, "Completed"),
-            MissionStatuses::Failed => write!(f, "Failed"),
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ToSql<MissionStatus, Pg> for MissionStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_str().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<MissionStatus, Pg> for MissionStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        match bytes.as_bytes() {
+            b"Draft" => Ok(MissionStatuses::Draft),
+            b"Open" => Ok(MissionStatuses::Open),
+            b"InProgress" => Ok(MissionStatuses::InProgress),
+            b"Completed" => Ok(MissionStatuses::Completed),
+            b"Failed" => Ok(MissionStatuses::Failed),
+            b"Cancelled" => Ok(MissionStatuses::Cancelled),
+            other => Err(format!(
+                "Unrecognized mission status: {}",
+                String::from_utf8_lossy(other)
+            )
+            .into()),
         }
     }
 }
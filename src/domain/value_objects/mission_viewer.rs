@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+/// Who is looking at a mission, for missions that are not open to everyone.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MissionViewer {
+    /// The signed-in brawler, when the request carried an access token.
+    pub brawler_id: Option<i32>,
+    /// The token from one of the mission's invite links.
+    pub invite_token: Option<String>,
+}
+
+/// `?invite=` on mission pages, so an invite link can show the mission it leads to.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct InviteTokenQuery {
+    pub invite: Option<String>,
+}
+
+impl MissionViewer {
+    pub fn new(brawler_id: Option<i32>, invite_token_query: InviteTokenQuery) -> Self {
+        Self {
+            brawler_id,
+            invite_token: invite_token_query
+                .invite
+                .map(|token| token.trim().to_string())
+                .filter(|token| !token.is_empty()),
+        }
+    }
+}
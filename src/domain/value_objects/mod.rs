@@ -1,5 +1,19 @@
+pub mod base64_image;
 pub mod brawler_model;
+pub mod crew_model;
+pub mod join_policy;
+pub mod join_request_model;
+pub mod mail_message;
 pub mod mission_filter;
+pub mod mission_invite_model;
 pub mod mission_model;
+pub mod mission_status_event_model;
 pub mod mission_statuses;
-pub mod uploaded_image;
\ No newline at end of file
+pub mod mission_viewer;
+pub mod pagination;
+pub mod password_model;
+pub mod tag_model;
+pub mod two_factor_model;
+pub mod uploaded_image;
+pub mod venue_model;
+pub mod waitlist_model;
\ No newline at end of file
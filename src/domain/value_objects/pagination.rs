@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::app_error::{AppError, AppResult};
+
+pub const DEFAULT_PAGE_LIMIT: i64 = 20;
+pub const MAX_PAGE_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PageQuery {
+    /// 1-based page number.
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+impl PageQuery {
+    /// The validated `(page, limit)`, falling back to the first page of
+    /// `DEFAULT_PAGE_LIMIT` items. `page * limit` is guaranteed to fit in an `i64`,
+    /// so callers can compute the offset without overflowing.
+    pub fn resolve(&self) -> AppResult<(i64, i64)> {
+        let page = self.page.unwrap_or(1);
+        if page < 1 {
+            return Err(AppError::Validation("page must be at least 1".to_string()));
+        }
+
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(AppError::Validation(format!(
+                "limit must be between 1 and {}",
+                MAX_PAGE_LIMIT
+            )));
+        }
+
+        if page.checked_mul(limit).is_none() {
+            return Err(AppError::Validation("page is too large".to_string()));
+        }
+
+        Ok((page, limit))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub limit: i64,
+    pub total: i64,
+    /// The page to ask for next, or `None` on the last page.
+    pub next_page: Option<i64>,
+}
+
+impl<T> Paginated<T> {
+    pub fn new(items: Vec<T>, page: i64, limit: i64, total: i64) -> Self {
+        // `resolve` already bounds `page * limit`; stay safe for callers that did not use it.
+        let next_page = page
+            .checked_mul(limit)
+            .is_some_and(|seen| seen < total)
+            .then(|| page + 1);
+        Self {
+            items,
+            page,
+            limit,
+            total,
+            next_page,
+        }
+    }
+}
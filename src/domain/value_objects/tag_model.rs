@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    app_error::{AppError, AppResult},
+    value_objects::pagination::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT},
+};
+
+pub const MAX_TAGS_PER_MISSION: usize = 10;
+pub const MAX_TAG_LENGTH: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TagCountModel {
+    pub name: String,
+    /// Browsable missions carrying the tag.
+    pub mission_count: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PopularTagsQuery {
+    pub limit: Option<i64>,
+}
+
+impl PopularTagsQuery {
+    pub fn limit(&self) -> AppResult<i64> {
+        let limit = self.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(AppError::Validation(format!(
+                "limit must be between 1 and {}",
+                MAX_PAGE_LIMIT
+            )));
+        }
+        Ok(limit)
+    }
+}
+
+/// Lowercases, trims and collapses inner whitespace so `Board  Games` and `board games`
+/// are the same tag. Duplicates are dropped, keeping the first occurrence.
+pub fn normalize_tags<S: AsRef<str>>(tags: &[S]) -> AppResult<Vec<String>> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = tag
+            .as_ref()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+
+        if tag.is_empty() || tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(AppError::Validation(format!(
+                "Tags must be between 1 and {} characters",
+                MAX_TAG_LENGTH
+            )));
+        }
+        if !tag
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == ' ' || c == '-')
+        {
+            return Err(AppError::Validation(format!(
+                "Tag {:?} may only contain letters, digits, spaces and hyphens",
+                tag
+            )));
+        }
+
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+/// Parses a comma-separated `tag,other tag` query parameter.
+pub fn parse_tag_list(tags: Option<&str>) -> AppResult<Vec<String>> {
+    let Some(tags) = tags else {
+        return Ok(Vec::new());
+    };
+    let tags: Vec<&str> = tags.split(',').filter(|tag| !tag.trim().is_empty()).collect();
+    normalize_tags(&tags)
+}
+
+/// Groups `(mission_id, tag)` pairs by mission, keeping each mission's tag order.
+pub fn group_mission_tags(pairs: Vec<(i32, String)>) -> HashMap<i32, Vec<String>> {
+    let mut grouped: HashMap<i32, Vec<String>> = HashMap::new();
+    for (mission_id, tag) in pairs {
+        grouped.entry(mission_id).or_default().push(tag);
+    }
+    grouped
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::app_error::{AppError, AppResult};
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VenueModel {
+    pub name: String,
+    #[serde(default)]
+    pub address: Option<String>,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl VenueModel {
+    pub fn validate(&self) -> AppResult<()> {
+        if self.name.trim().is_empty() || self.name.len() > 255 {
+            return Err(AppError::Validation(
+                "Venue name must be between 1 and 255 characters".to_string(),
+            ));
+        }
+        validate_coordinates(self.latitude, self.longitude)
+    }
+}
+
+pub fn validate_coordinates(latitude: f64, longitude: f64) -> AppResult<()> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(AppError::Validation(
+            "Latitude must be between -90 and 90".to_string(),
+        ));
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(AppError::Validation(
+            "Longitude must be between -180 and 180".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Great-circle distance between two points, in kilometres.
+pub fn haversine_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (lat1, lng1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lng2) = (to.0.to_radians(), to.1.to_radians());
+
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lng2 - lng1) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// A latitude/longitude box that contains every point within `radius_km` of `center`.
+/// It is only a cheap pre-filter; callers still have to check the exact distance.
+/// The longitude range is `None` when the box would wrap around a pole or the antimeridian.
+pub fn bounding_box(center: (f64, f64), radius_km: f64) -> ((f64, f64), Option<(f64, f64)>) {
+    let (latitude, longitude) = center;
+    let lat_delta = (radius_km / EARTH_RADIUS_KM).to_degrees();
+    let lat_range = (latitude - lat_delta, latitude + lat_delta);
+
+    if lat_range.0 <= -90.0 || lat_range.1 >= 90.0 {
+        return (lat_range, None);
+    }
+
+    let lng_delta = (radius_km / (EARTH_RADIUS_KM * latitude.to_radians().cos())).to_degrees();
+    let lng_range = (longitude - lng_delta, longitude + lng_delta);
+    if lng_range.0 < -180.0 || lng_range.1 > 180.0 {
+        return (lat_range, None);
+    }
+
+    (lat_range, Some(lng_range))
+}
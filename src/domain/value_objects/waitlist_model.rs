@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// What a join ended up doing: a seat in the crew, a place in the queue, or a request
+/// waiting for the chief.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrewJoinOutcome {
+    Joined,
+    Waitlisted { position: u32 },
+    Requested,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitlistPositionModel {
+    pub mission_id: i32,
+    /// 1 is next in line.
+    pub position: u32,
+    pub waitlist_size: u32,
+}
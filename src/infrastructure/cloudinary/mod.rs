@@ -1,4 +1,4 @@
-use crate::config::{config_loader::get_cloundinary_env, config_model::CloudinaryEnv};
+use crate::config::config_model::CloudinaryEnv;
 use crate::domain::value_objects::base64_image::Base64Image;
 use crate::domain::value_objects::uploaded_image::UploadedImage;
 use anyhow::{Context, Ok, Result};
@@ -15,7 +15,7 @@ pub struct UploadImageOptions {
 
 fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result<Form> {
     let mut form = Form::new();
-    let timestamp = Utc::now().timestamp_millis().to_string();
+    let timestamp = Utc::now().timestamp().to_string();
     let mut hasher = Sha1::new();
 
     let mut params_to_sign: HashMap<String, String> = HashMap::new();
@@ -52,19 +52,17 @@ fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result
 
     form = form.text("signature", format!("{:x}", hasher.finalize()));
     form = form.text("api_key", cloud_env.api_key.clone());
-    form = form.text("timestamp", timestamp.clone());
 
     Ok(form)
 }
 
 pub async fn upload(
+    cloud_env: &CloudinaryEnv,
     base64_image: Base64Image,
     option: UploadImageOptions,
 ) -> Result<UploadedImage> {
-    let cloud_env = get_cloundinary_env()?;
-
     let file = Part::text(base64_image.into_inner());
-    let form = form_builder(option, &cloud_env)?;
+    let form = form_builder(option, cloud_env)?;
     let multipart = form.part("file", file);
     let client = reqwest::Client::new();
     let url = format!(
@@ -83,4 +81,33 @@ pub async fn upload(
     let json: UploadedImage =
         serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
     Ok(json)
-}
\ No newline at end of file
+}
+pub async fn destroy(cloud_env: &CloudinaryEnv, public_id: &str) -> Result<()> {
+    let option = UploadImageOptions {
+        folder: None,
+        public_id: Some(public_id.to_string()),
+        transformation: None,
+    };
+    let form = form_builder(option, cloud_env)?;
+    let client = reqwest::Client::new();
+    let url = format!(
+        "https://api.cloudinary.com/v1_1/{}/image/destroy",
+        cloud_env.cloud_name
+    );
+
+    let response = client
+        .post(&url)
+        .multipart(form)
+        .send()
+        .await
+        .context(format!("destroy on {}", url))?;
+
+    let text = response.text().await?;
+    let json: serde_json::Value =
+        serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
+    // "not found" means the image is already gone, which is what we wanted anyway.
+    match json["result"].as_str() {
+        Some("ok") | Some("not found") => Ok(()),
+        _ => Err(anyhow::anyhow!("failed to destroy {}: {}", public_id, text)),
+    }
+}
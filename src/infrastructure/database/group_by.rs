@@ -0,0 +1,42 @@
+//! Column groupings diesel cannot infer from `schema.rs`. Kept out of that file because
+//! `diesel print-schema` regenerates it and would drop anything added by hand.
+
+use crate::infrastructure::database::schema::{brawlers, missions};
+
+// Listings group by `missions.id` and `brawlers.id` while selecting the rest of both rows,
+// which Postgres allows because each id is its table's primary key.
+diesel::allow_columns_to_appear_in_same_group_by_clause!(
+    missions::id,
+    missions::name,
+    missions::description,
+    missions::status,
+    missions::chief_id,
+    missions::created_at,
+    missions::updated_at,
+    missions::deleted_at,
+    missions::starts_at,
+    missions::ends_at,
+    missions::timezone,
+    missions::venue_name,
+    missions::venue_address,
+    missions::latitude,
+    missions::longitude,
+    missions::max_crew,
+    missions::min_crew,
+    missions::join_policy,
+    missions::search_vector,
+    brawlers::id,
+    brawlers::username,
+    brawlers::password,
+    brawlers::created_at,
+    brawlers::updated_at,
+    brawlers::email,
+    brawlers::totp_secret,
+    brawlers::totp_enabled,
+    brawlers::display_name,
+    brawlers::avatar_url,
+    brawlers::avatar_public_id,
+    brawlers::totp_failed_attempts,
+    brawlers::totp_locked_until,
+    brawlers::totp_last_step,
+);
@@ -1,5 +0,0 @@
--- Your SQL goes here
-ALTER TABLE brawlers
-ADD COLUMN display_name VARCHAR(50) NOT NULL,
-ADD COLUMN avatar_url VARCHAR(512),
-ADD COLUMN avatar_public_id VARCHAR(255);
\ No newline at end of file
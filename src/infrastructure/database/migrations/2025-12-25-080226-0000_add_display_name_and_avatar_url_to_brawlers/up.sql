@@ -1,5 +0,0 @@
--- Your SQL goes here
-ALTER TABLE brawlers
-ADD display_name VARCHAR(50) NOT NULL DEFAULT '',
-    avatar_url VARCHAR(512),
-    avatar_public_id VARCHAR(255);
\ No newline at end of file
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE sessions (
+    jti VARCHAR(64) PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP
+);
+
+ALTER TABLE
+    sessions
+ADD
+    CONSTRAINT fk_session_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_sessions_brawler_id ON sessions (brawler_id);
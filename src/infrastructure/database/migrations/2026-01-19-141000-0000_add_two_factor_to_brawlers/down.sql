@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS recovery_codes;
+
+ALTER TABLE brawlers
+DROP COLUMN totp_enabled,
+DROP COLUMN totp_secret;
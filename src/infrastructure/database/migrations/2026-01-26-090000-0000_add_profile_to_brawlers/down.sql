@@ -1,5 +1,5 @@
 -- This file should undo anything in `up.sql`
 ALTER TABLE brawlers
+DROP COLUMN avatar_public_id,
 DROP COLUMN avatar_url,
-DROP COLUMN display_name,
-DROP COLUMN avatar_public_id;
\ No newline at end of file
+DROP COLUMN display_name;
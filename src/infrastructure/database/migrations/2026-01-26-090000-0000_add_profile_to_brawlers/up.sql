@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN display_name VARCHAR(50),
+ADD COLUMN avatar_url TEXT,
+ADD COLUMN avatar_public_id VARCHAR(255);
+
+-- Existing brawlers start out showing their username.
+UPDATE brawlers SET display_name = LEFT(username, 50);
+
+ALTER TABLE brawlers
+ALTER COLUMN display_name SET NOT NULL;
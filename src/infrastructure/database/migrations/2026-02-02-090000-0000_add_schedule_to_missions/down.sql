@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_missions_starts_at;
+
+ALTER TABLE missions
+DROP CONSTRAINT IF EXISTS check_mission_ends_after_start,
+DROP COLUMN timezone,
+DROP COLUMN ends_at,
+DROP COLUMN starts_at;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN starts_at TIMESTAMP,
+ADD COLUMN ends_at TIMESTAMP,
+ADD COLUMN timezone VARCHAR(64) NOT NULL DEFAULT 'UTC';
+
+-- Missions created before scheduling existed are treated as one-hour meetups
+-- that started when they were created.
+UPDATE missions
+SET starts_at = created_at,
+    ends_at = created_at + INTERVAL '1 hour';
+
+ALTER TABLE missions
+ALTER COLUMN starts_at SET NOT NULL,
+ALTER COLUMN ends_at SET NOT NULL,
+ADD CONSTRAINT check_mission_ends_after_start CHECK (ends_at > starts_at);
+
+CREATE INDEX idx_missions_starts_at ON missions (starts_at);
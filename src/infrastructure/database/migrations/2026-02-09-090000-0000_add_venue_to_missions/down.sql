@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_missions_coordinates;
+
+ALTER TABLE missions
+DROP CONSTRAINT IF EXISTS check_mission_coordinates,
+DROP CONSTRAINT IF EXISTS check_mission_longitude,
+DROP CONSTRAINT IF EXISTS check_mission_latitude,
+DROP COLUMN longitude,
+DROP COLUMN latitude,
+DROP COLUMN venue_address,
+DROP COLUMN venue_name;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN venue_name VARCHAR(255),
+ADD COLUMN venue_address TEXT,
+ADD COLUMN latitude DOUBLE PRECISION,
+ADD COLUMN longitude DOUBLE PRECISION,
+ADD CONSTRAINT check_mission_latitude CHECK (latitude BETWEEN -90 AND 90),
+ADD CONSTRAINT check_mission_longitude CHECK (longitude BETWEEN -180 AND 180),
+ADD CONSTRAINT check_mission_coordinates CHECK ((latitude IS NULL) = (longitude IS NULL));
+
+CREATE INDEX idx_missions_coordinates ON missions (latitude, longitude);
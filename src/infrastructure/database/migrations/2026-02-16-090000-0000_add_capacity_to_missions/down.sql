@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions
+DROP CONSTRAINT IF EXISTS check_mission_crew_bounds,
+DROP COLUMN min_crew,
+DROP COLUMN max_crew;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN max_crew INTEGER NOT NULL DEFAULT 10,
+ADD COLUMN min_crew INTEGER NOT NULL DEFAULT 1,
+ADD CONSTRAINT check_mission_crew_bounds CHECK (min_crew >= 1 AND min_crew <= max_crew AND max_crew <= 100);
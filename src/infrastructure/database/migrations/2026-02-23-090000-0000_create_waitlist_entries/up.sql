@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE waitlist_entries (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    joined_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    waitlist_entries
+ADD
+    CONSTRAINT fk_waitlist_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_waitlist_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT unique_waitlist_entry UNIQUE (mission_id, brawler_id);
+
+-- Positions are the order of `id` within a mission.
+CREATE INDEX idx_waitlist_entries_mission_id ON waitlist_entries (mission_id, id);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS join_requests;
+
+ALTER TABLE missions
+DROP CONSTRAINT IF EXISTS check_mission_join_policy,
+DROP COLUMN join_policy;
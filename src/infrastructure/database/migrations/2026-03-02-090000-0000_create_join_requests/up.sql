@@ -0,0 +1,26 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN join_policy VARCHAR(20) NOT NULL DEFAULT 'Open',
+ADD CONSTRAINT check_mission_join_policy CHECK (join_policy IN ('Open', 'ApprovalRequired', 'InviteOnly'));
+
+CREATE TABLE join_requests (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'Pending',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    decided_at TIMESTAMP
+);
+
+ALTER TABLE
+    join_requests
+ADD
+    CONSTRAINT fk_join_request_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_join_request_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT check_join_request_status CHECK (status IN ('Pending', 'Approved', 'Rejected'));
+
+-- A brawler may ask again after a rejection, but only one request can be waiting.
+CREATE UNIQUE INDEX unique_pending_join_request ON join_requests (mission_id, brawler_id)
+WHERE status = 'Pending';
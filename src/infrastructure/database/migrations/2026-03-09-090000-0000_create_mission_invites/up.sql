@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE mission_invites (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    created_by INTEGER NOT NULL,
+    token_hash VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP,
+    max_uses INTEGER,
+    uses INTEGER NOT NULL DEFAULT 0,
+    revoked_at TIMESTAMP
+);
+
+ALTER TABLE
+    mission_invites
+ADD
+    CONSTRAINT fk_mission_invite_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_mission_invite_brawler FOREIGN KEY (created_by) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT unique_mission_invite_token_hash UNIQUE (token_hash),
+ADD
+    CONSTRAINT check_mission_invite_uses CHECK (max_uses IS NULL OR (max_uses >= 1 AND uses <= max_uses));
+
+CREATE INDEX idx_mission_invites_mission_id ON mission_invites (mission_id);
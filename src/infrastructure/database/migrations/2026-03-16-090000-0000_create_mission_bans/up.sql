@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE mission_bans (
+    mission_id INTEGER NOT NULL,
+    brawler_id INTEGER NOT NULL,
+    banned_by INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (mission_id, brawler_id)
+);
+
+ALTER TABLE
+    mission_bans
+ADD
+    CONSTRAINT fk_mission_ban_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_mission_ban_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id),
+ADD
+    CONSTRAINT fk_mission_ban_banned_by FOREIGN KEY (banned_by) REFERENCES brawlers(id);
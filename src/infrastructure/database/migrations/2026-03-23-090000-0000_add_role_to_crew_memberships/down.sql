@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE crew_memberships
+DROP CONSTRAINT IF EXISTS check_crew_membership_role,
+DROP COLUMN role;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE crew_memberships
+ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'Member',
+ADD CONSTRAINT check_crew_membership_role CHECK (role IN ('Member', 'CoChief'));
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+-- Great-circle distance in kilometres, so radius filters and distance sorting can be paged in SQL.
+CREATE FUNCTION haversine_km(
+    lat1 DOUBLE PRECISION,
+    lng1 DOUBLE PRECISION,
+    lat2 DOUBLE PRECISION,
+    lng2 DOUBLE PRECISION
+) RETURNS DOUBLE PRECISION
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT 2 * 6371.0 * asin(least(1.0, sqrt(
+        power(sin(radians(lat2 - lat1) / 2), 2)
+        + cos(radians(lat1)) * cos(radians(lat2)) * power(sin(radians(lng2 - lng1) / 2), 2)
+    )))
+$$;
+
+CREATE INDEX idx_missions_created_at ON missions (created_at DESC, id DESC)
+WHERE deleted_at IS NULL;
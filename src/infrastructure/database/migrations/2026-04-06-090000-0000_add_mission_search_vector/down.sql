@@ -0,0 +1,15 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS idx_missions_search_vector;
+
+DROP TRIGGER IF EXISTS missions_search_vector_update ON missions;
+
+DROP FUNCTION IF EXISTS missions_search_vector_update();
+
+ALTER TABLE missions
+DROP COLUMN IF EXISTS search_vector;
+
+DROP FUNCTION IF EXISTS mission_search_headline(TEXT, TEXT, TEXT);
+
+DROP FUNCTION IF EXISTS mission_search_query(TEXT);
+
+DROP FUNCTION IF EXISTS mission_search_document(TEXT, TEXT);
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+-- The search configuration lives here only; the trigger and the API both go through these functions.
+CREATE FUNCTION mission_search_document(name TEXT, description TEXT) RETURNS tsvector
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$
+    SELECT setweight(to_tsvector('english', coalesce(name, '')), 'A')
+        || setweight(to_tsvector('english', coalesce(description, '')), 'B')
+$$;
+
+CREATE FUNCTION mission_search_query(q TEXT) RETURNS tsquery
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT websearch_to_tsquery('english', q)
+$$;
+
+CREATE FUNCTION mission_search_headline(name TEXT, description TEXT, q TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$
+    SELECT CASE WHEN q IS NULL THEN NULL ELSE ts_headline(
+        'english',
+        concat_ws(' - ', name, description),
+        mission_search_query(q),
+        'StartSel=<b>, StopSel=</b>, MaxWords=35, MinWords=15'
+    ) END
+$$;
+
+ALTER TABLE missions
+ADD COLUMN search_vector tsvector NOT NULL DEFAULT ''::tsvector;
+
+CREATE FUNCTION missions_search_vector_update() RETURNS trigger
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    NEW.search_vector := mission_search_document(NEW.name, NEW.description);
+    RETURN NEW;
+END
+$$;
+
+CREATE TRIGGER missions_search_vector_update
+BEFORE INSERT OR UPDATE OF name, description ON missions
+FOR EACH ROW EXECUTE FUNCTION missions_search_vector_update();
+
+UPDATE missions SET search_vector = mission_search_document(name, description);
+
+CREATE INDEX idx_missions_search_vector ON missions USING GIN (search_vector);
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(50) NOT NULL UNIQUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE TABLE mission_tags (
+    mission_id INTEGER NOT NULL,
+    tag_id INTEGER NOT NULL,
+    PRIMARY KEY (mission_id, tag_id)
+);
+
+ALTER TABLE
+    mission_tags
+ADD
+    CONSTRAINT fk_mission_tag_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_mission_tag_tag FOREIGN KEY (tag_id) REFERENCES tags(id);
+
+CREATE INDEX idx_mission_tags_tag_id ON mission_tags (tag_id, mission_id);
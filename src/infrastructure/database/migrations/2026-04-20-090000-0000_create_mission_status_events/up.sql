@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE mission_status_events (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    from_status VARCHAR(255) NOT NULL,
+    to_status VARCHAR(255) NOT NULL,
+    actor_id INTEGER NOT NULL,
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    mission_status_events
+ADD
+    CONSTRAINT fk_mission_status_event_mission FOREIGN KEY (mission_id) REFERENCES missions(id),
+ADD
+    CONSTRAINT fk_mission_status_event_actor FOREIGN KEY (actor_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_status_events_mission_id ON mission_status_events (mission_id, created_at);
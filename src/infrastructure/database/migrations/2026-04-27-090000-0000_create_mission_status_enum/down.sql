@@ -0,0 +1,35 @@
+-- This file should undo anything in `up.sql`
+-- Draft and Cancelled missions have no VARCHAR equivalent to fall back to.
+UPDATE
+    missions
+SET
+    status = 'Open'
+WHERE
+    status = 'Draft';
+
+UPDATE
+    missions
+SET
+    status = 'Failed'
+WHERE
+    status = 'Cancelled';
+
+DELETE FROM
+    mission_status_events
+WHERE
+    from_status IN ('Draft', 'Cancelled')
+    OR to_status IN ('Draft', 'Cancelled');
+
+ALTER TABLE
+    mission_status_events
+ALTER COLUMN
+    from_status TYPE VARCHAR(255) USING from_status::text,
+ALTER COLUMN
+    to_status TYPE VARCHAR(255) USING to_status::text;
+
+ALTER TABLE
+    missions
+ALTER COLUMN
+    status TYPE VARCHAR(255) USING status::text;
+
+DROP TYPE IF EXISTS mission_status;
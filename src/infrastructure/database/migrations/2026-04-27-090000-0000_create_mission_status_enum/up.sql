@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TYPE mission_status AS ENUM (
+    'Draft',
+    'Open',
+    'InProgress',
+    'Completed',
+    'Failed',
+    'Cancelled'
+);
+
+ALTER TABLE
+    missions
+ALTER COLUMN
+    status TYPE mission_status USING status::mission_status;
+
+ALTER TABLE
+    mission_status_events
+ALTER COLUMN
+    from_status TYPE mission_status USING from_status::mission_status,
+ALTER COLUMN
+    to_status TYPE mission_status USING to_status::mission_status;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE brawlers
+    DROP COLUMN totp_last_step,
+    DROP COLUMN totp_locked_until,
+    DROP COLUMN totp_failed_attempts;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+    ADD COLUMN totp_failed_attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN totp_locked_until TIMESTAMP,
+    ADD COLUMN totp_last_step BIGINT;
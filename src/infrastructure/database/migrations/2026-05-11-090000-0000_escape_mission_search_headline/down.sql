@@ -0,0 +1,13 @@
+-- This file should undo anything in `up.sql`
+CREATE OR REPLACE FUNCTION mission_search_headline(name TEXT, description TEXT, q TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$
+    SELECT CASE WHEN q IS NULL THEN NULL ELSE ts_headline(
+        'english',
+        concat_ws(' - ', name, description),
+        mission_search_query(q),
+        'StartSel=<b>, StopSel=</b>, MaxWords=35, MinWords=15'
+    ) END
+$$;
+
+DROP FUNCTION html_escape(TEXT);
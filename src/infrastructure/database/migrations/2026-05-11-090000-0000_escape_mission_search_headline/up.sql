@@ -0,0 +1,25 @@
+-- Your SQL goes here
+-- Names and descriptions are user text, so they are HTML-escaped before highlighting;
+-- the only markup left in a headline is the <b> around matches.
+CREATE FUNCTION html_escape(value TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT replace(replace(replace(replace(replace(
+        value,
+        '&', '&amp;'),
+        '<', '&lt;'),
+        '>', '&gt;'),
+        '"', '&quot;'),
+        '''', '&#39;')
+$$;
+
+CREATE OR REPLACE FUNCTION mission_search_headline(name TEXT, description TEXT, q TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$
+    SELECT CASE WHEN q IS NULL THEN NULL ELSE ts_headline(
+        'english',
+        html_escape(concat_ws(' - ', name, description)),
+        mission_search_query(q),
+        'StartSel=<b>, StopSel=</b>, MaxWords=35, MinWords=15'
+    ) END
+$$;
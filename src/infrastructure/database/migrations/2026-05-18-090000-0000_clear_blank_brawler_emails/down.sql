@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+-- Nothing to undo: blank emails were never meant to be stored.
@@ -0,0 +1,5 @@
+-- Registration used to store a blank email as is. It is no address, and the unique
+-- constraint let only one brawler have it.
+UPDATE brawlers
+SET email = NULL
+WHERE btrim(email) = '';
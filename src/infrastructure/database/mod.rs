@@ -1,3 +1,5 @@
+pub mod group_by;
 pub mod postgresql_connection;
 pub mod repositories;
 pub mod schema;
+pub mod sql_functions;
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::insert_into, QueryDsl, SelectableHelper, BoolExpressionMethods};
-use crate::infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{brawlers, crew_memberships, missions}};
-use crate::domain::{entities::{brawlers::{BrawlerEntity, RegisterBrawlerEntity}, missions::MissionEntity}, repositories::brawlers::BrawlerRepository};
+use chrono::NaiveDateTime;
+use diesel::{Connection, ExpressionMethods, NullableExpressionMethods, RunQueryDsl, dsl::{count, insert_into}, QueryDsl, SelectableHelper, BoolExpressionMethods};
+use crate::infrastructure::database::{postgresql_connection::PgPoolSquad, repositories::mission_viewing::load_mission_tags, schema::{brawlers, crew_memberships, missions}};
+use crate::domain::{entities::{brawlers::{BrawlerEntity, BrawlerProfileEntity, RegisterBrawlerEntity}, missions::MissionEntity}, repositories::brawlers::BrawlerRepository};
 
 pub struct BrawlerPostgres {
     db_pool: Arc<PgPoolSquad>,
@@ -39,35 +40,150 @@ impl BrawlerRepository for BrawlerPostgres {
         Ok(result)
     }
 
-    async fn update_avatar(&self, _id: i32, avatar_url: String) -> Result<String> {
-        // TODO: Implement actual database update when schema supports avatar
-        Ok(avatar_url)
+    async fn find_by_id(&self, id: i32) -> Result<BrawlerEntity> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .filter(brawlers::id.eq(id))
+            .select(BrawlerEntity::as_select())
+            .first::<BrawlerEntity>(&mut connection)?;
+
+        Ok(result)
     }
 
-    async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
+    async fn update_password(&self, id: i32, hashed_password: String) -> Result<()> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
-        let count = crew_memberships::table
-            .filter(crew_memberships::mission_id.eq(mission_id))
-            .count()
-            .get_result::<i64>(&mut connection)?;
-        Ok(count as u32)
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(id))
+            .set(brawlers::password.eq(hashed_password))
+            .execute(&mut connection)?;
+
+        Ok(())
     }
 
-    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<MissionEntity>> {
+    async fn update_totp(&self, id: i32, totp_secret: Option<String>, totp_enabled: bool) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(id))
+            .set((
+                brawlers::totp_secret.eq(totp_secret),
+                brawlers::totp_enabled.eq(totp_enabled),
+            ))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
+    async fn record_totp_step(&self, id: i32, step: i64) -> Result<bool> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(id))
+            .filter(
+                brawlers::totp_last_step
+                    .is_null()
+                    .or(brawlers::totp_last_step.lt(step)),
+            )
+            .set(brawlers::totp_last_step.eq(step))
+            .execute(&mut connection)?;
+
+        Ok(updated > 0)
+    }
+
+    async fn record_second_factor_failure(&self, id: i32, max_attempts: i32, locked_until: NaiveDateTime) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        connection.transaction::<_, anyhow::Error, _>(|conn| {
+            let failed_attempts = diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(id))
+                .set(brawlers::totp_failed_attempts.eq(brawlers::totp_failed_attempts + 1))
+                .returning(brawlers::totp_failed_attempts)
+                .get_result::<i32>(conn)?;
+
+            if failed_attempts >= max_attempts {
+                diesel::update(brawlers::table)
+                    .filter(brawlers::id.eq(id))
+                    .set((
+                        brawlers::totp_failed_attempts.eq(0),
+                        brawlers::totp_locked_until.eq(locked_until),
+                    ))
+                    .execute(conn)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn reset_second_factor_failures(&self, id: i32) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(id))
+            .set((
+                brawlers::totp_failed_attempts.eq(0),
+                brawlers::totp_locked_until.eq(None::<NaiveDateTime>),
+            ))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
+    async fn update_email(&self, id: i32, email: Option<String>) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(id))
+            .set(brawlers::email.eq(email))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
+    async fn update_avatar(&self, id: i32, avatar_url: String, avatar_public_id: Option<String>) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(brawlers::table)
+            .filter(brawlers::id.eq(id))
+            .set((
+                brawlers::avatar_url.eq(avatar_url),
+                brawlers::avatar_public_id.eq(avatar_public_id),
+            ))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
+    async fn get_missions(&self, brawler_id: i32) -> Result<Vec<(MissionEntity, BrawlerProfileEntity, i64)>> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
         
-        let subquery = crew_memberships::table
-            .filter(crew_memberships::brawler_id.eq(brawler_id))
-            .select(crew_memberships::mission_id);
+        // Aliased so it does not clash with the crew join used for counting.
+        let my_memberships = diesel::alias!(crew_memberships as my_memberships);
+        let subquery = my_memberships
+            .filter(my_memberships.field(crew_memberships::brawler_id).eq(brawler_id))
+            .select(my_memberships.field(crew_memberships::mission_id));
 
         let missions = missions::table
+            .inner_join(brawlers::table)
+            .left_join(crew_memberships::table)
             .filter(
                 missions::chief_id.eq(brawler_id)
                 .or(missions::id.eq_any(subquery))
             )
             .filter(missions::deleted_at.is_null())
-            .select(MissionEntity::as_select())
+            .group_by((missions::id, brawlers::id))
+            .select((
+                MissionEntity::as_select(),
+                BrawlerProfileEntity::as_select(),
+                count(crew_memberships::brawler_id.nullable()),
+            ))
             .load(&mut connection)?;
         Ok(missions)
     }
+
+    async fn mission_tags(&self, mission_ids: &[i32]) -> Result<Vec<(i32, String)>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+        load_mission_tags(&mut connection, mission_ids)
+    }
 }
@@ -1,65 +1,401 @@
-use anyhow::{Ok, Result};
-use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::delete, insert_into, r2d2::{ConnectionManager, PooledConnection}, PgConnection};
-use std::sync::Arc;
+use anyhow::Result;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, OptionalExtension,
+    PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, exists, now, select, update},
+    insert_into,
+    r2d2::{ConnectionManager, PooledConnection},
+};
 
 use crate::{
     domain::{
-        entities::crew_memberships::CrewMembershipEntity,
+        entities::{
+            brawlers::BrawlerProfileEntity,
+            crew_memberships::NewCrewMembership,
+            join_requests::{JoinRequestEntity, NewJoinRequest},
+            mission_bans::NewMissionBan,
+            mission_invites::{MissionInviteEntity, NewMissionInvite},
+            missions::MissionEntity,
+            waitlist_entries::{NewWaitlistEntry, WaitlistEntryEntity},
+        },
         repositories::crew_operation::CrewOperationRepository,
+        value_objects::join_request_model::JoinRequestStatuses,
+    },
+    infrastructure::database::schema::{
+        brawlers, crew_memberships, join_requests, mission_bans, mission_invites, missions,
+        waitlist_entries,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::crew_memberships},
 };
 
-pub struct CrewOperationPostgres {
-    db_pool: Arc<PgPoolSquad>,
-}
+pub struct CrewOperationPostgres;
 
 impl CrewOperationPostgres {
-    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
-        Self { db_pool }
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for CrewOperationPostgres {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-#[async_trait]
 impl CrewOperationRepository for CrewOperationPostgres {
-    async fn join(&self, crew_member_ships: CrewMembershipEntity) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    type Connection = PooledConnection<ConnectionManager<PgConnection>>;
+
+    fn lock_mission(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<MissionEntity> {
+        let mission = missions::table
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .select(MissionEntity::as_select())
+            .for_update()
+            .first::<MissionEntity>(conn)?;
+        Ok(mission)
+    }
+
+    fn crew_counting(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<u32> {
+        let count = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(u32::try_from(count)?)
+    }
+
+    fn is_crew_member(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<bool> {
+        let is_member = select(exists(
+            crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .filter(crew_memberships::brawler_id.eq(brawler_id)),
+        ))
+        .get_result::<bool>(conn)?;
+        Ok(is_member)
+    }
+
+    fn join(
+        &self,
+        conn: &mut Self::Connection,
+        crew_memberships: NewCrewMembership,
+    ) -> Result<()> {
         insert_into(crew_memberships::table)
-            .values(crew_member_ships)
-            .execute(&mut conn)?;
+            .values(crew_memberships)
+            .execute(conn)?;
         Ok(())
     }
 
-    async fn leave(&self, crew_member_ships: CrewMembershipEntity) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        delete(crew_memberships::table)
-            .filter(crew_memberships::brawler_id.eq(crew_member_ships.brawler_id))
-            .filter(crew_memberships::mission_id.eq(crew_member_ships.mission_id))
-            .execute(&mut conn)?;
+    fn leave(
+        &self,
+        conn: &mut Self::Connection,
+        crew_memberships: NewCrewMembership,
+    ) -> Result<usize> {
+        let deleted = delete(crew_memberships::table)
+            .filter(crew_memberships::brawler_id.eq(crew_memberships.brawler_id))
+            .filter(crew_memberships::mission_id.eq(crew_memberships.mission_id))
+            .execute(conn)?;
+        Ok(deleted)
+    }
+
+    fn set_crew_role(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+        role: String,
+    ) -> Result<()> {
+        update(crew_memberships::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .set(crew_memberships::role.eq(role))
+            .execute(conn)?;
         Ok(())
     }
 
-    fn for_insert_transaction_test(
+    fn set_chief(
         &self,
-        conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
-        crew_memberships: CrewMembershipEntity,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        chief_id: i32,
     ) -> Result<()> {
-         insert_into(crew_memberships::table)
-            .values(crew_memberships)
+        update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .set(missions::chief_id.eq(chief_id))
             .execute(conn)?;
         Ok(())
     }
 
-    fn for_delete_transaction_test(
+    fn join_waitlist(
         &self,
-        conn: &mut PooledConnection<ConnectionManager<PgConnection>>,
-        crew_memberships: CrewMembershipEntity,
+        conn: &mut Self::Connection,
+        new_waitlist_entry: NewWaitlistEntry,
     ) -> Result<()> {
-         delete(crew_memberships::table)
-            .filter(crew_memberships::brawler_id.eq(crew_memberships.brawler_id))
-            .filter(crew_memberships::mission_id.eq(crew_memberships.mission_id))
+        insert_into(waitlist_entries::table)
+            .values(new_waitlist_entry)
             .execute(conn)?;
         Ok(())
     }
+
+    fn leave_waitlist(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<usize> {
+        let removed = delete(waitlist_entries::table)
+            .filter(waitlist_entries::mission_id.eq(mission_id))
+            .filter(waitlist_entries::brawler_id.eq(brawler_id))
+            .execute(conn)?;
+        Ok(removed)
+    }
+
+    fn waitlist_head(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<Option<WaitlistEntryEntity>> {
+        let head = waitlist_entries::table
+            .filter(waitlist_entries::mission_id.eq(mission_id))
+            .order(waitlist_entries::id.asc())
+            .select(WaitlistEntryEntity::as_select())
+            .first::<WaitlistEntryEntity>(conn)
+            .optional()?;
+        Ok(head)
+    }
+
+    fn waitlist_position(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<Option<u32>> {
+        let Some(entry_id) = waitlist_entries::table
+            .filter(waitlist_entries::mission_id.eq(mission_id))
+            .filter(waitlist_entries::brawler_id.eq(brawler_id))
+            .select(waitlist_entries::id)
+            .first::<i32>(conn)
+            .optional()?
+        else {
+            return Ok(None);
+        };
+
+        let ahead_and_self = waitlist_entries::table
+            .filter(waitlist_entries::mission_id.eq(mission_id))
+            .filter(waitlist_entries::id.le(entry_id))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(Some(u32::try_from(ahead_and_self)?))
+    }
+
+    fn waitlist_size(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<u32> {
+        let size = waitlist_entries::table
+            .filter(waitlist_entries::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(conn)?;
+        Ok(u32::try_from(size)?)
+    }
+
+    fn create_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        new_join_request: NewJoinRequest,
+    ) -> Result<()> {
+        insert_into(join_requests::table)
+            .values(new_join_request)
+            .execute(conn)?;
+        Ok(())
+    }
+
+    fn has_pending_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<bool> {
+        let is_pending = select(exists(
+            join_requests::table
+                .filter(join_requests::mission_id.eq(mission_id))
+                .filter(join_requests::brawler_id.eq(brawler_id))
+                .filter(join_requests::status.eq(JoinRequestStatuses::Pending.to_string())),
+        ))
+        .get_result::<bool>(conn)?;
+        Ok(is_pending)
+    }
+
+    fn pending_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        request_id: i32,
+    ) -> Result<Option<JoinRequestEntity>> {
+        let join_request = join_requests::table
+            .filter(join_requests::id.eq(request_id))
+            .filter(join_requests::mission_id.eq(mission_id))
+            .filter(join_requests::status.eq(JoinRequestStatuses::Pending.to_string()))
+            .select(JoinRequestEntity::as_select())
+            .first::<JoinRequestEntity>(conn)
+            .optional()?;
+        Ok(join_request)
+    }
+
+    fn pending_join_requests(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<Vec<(JoinRequestEntity, BrawlerProfileEntity)>> {
+        let join_requests = join_requests::table
+            .inner_join(brawlers::table)
+            .filter(join_requests::mission_id.eq(mission_id))
+            .filter(join_requests::status.eq(JoinRequestStatuses::Pending.to_string()))
+            .order(join_requests::id.asc())
+            .select((
+                JoinRequestEntity::as_select(),
+                BrawlerProfileEntity::as_select(),
+            ))
+            .load::<(JoinRequestEntity, BrawlerProfileEntity)>(conn)?;
+        Ok(join_requests)
+    }
+
+    fn decide_join_request(
+        &self,
+        conn: &mut Self::Connection,
+        request_id: i32,
+        status: String,
+    ) -> Result<()> {
+        update(join_requests::table)
+            .filter(join_requests::id.eq(request_id))
+            .set((
+                join_requests::status.eq(status),
+                join_requests::decided_at.eq(now),
+            ))
+            .execute(conn)?;
+        Ok(())
+    }
+
+    fn create_invite(
+        &self,
+        conn: &mut Self::Connection,
+        new_mission_invite: NewMissionInvite,
+    ) -> Result<MissionInviteEntity> {
+        let invite = insert_into(mission_invites::table)
+            .values(new_mission_invite)
+            .returning(MissionInviteEntity::as_returning())
+            .get_result::<MissionInviteEntity>(conn)?;
+        Ok(invite)
+    }
+
+    fn usable_invite(
+        &self,
+        conn: &mut Self::Connection,
+        token_hash: String,
+    ) -> Result<Option<MissionInviteEntity>> {
+        let invite = mission_invites::table
+            .filter(mission_invites::token_hash.eq(token_hash))
+            .filter(invite_is_usable())
+            .select(MissionInviteEntity::as_select())
+            .first::<MissionInviteEntity>(conn)
+            .optional()?;
+        Ok(invite)
+    }
+
+    fn consume_invite(
+        &self,
+        conn: &mut Self::Connection,
+        invite_id: i32,
+    ) -> Result<bool> {
+        let consumed = update(mission_invites::table)
+            .filter(mission_invites::id.eq(invite_id))
+            .filter(invite_is_usable())
+            .set(mission_invites::uses.eq(mission_invites::uses + 1))
+            .execute(conn)?;
+        Ok(consumed == 1)
+    }
+
+    fn active_invites(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+    ) -> Result<Vec<MissionInviteEntity>> {
+        let invites = mission_invites::table
+            .filter(mission_invites::mission_id.eq(mission_id))
+            .filter(invite_is_usable())
+            .order(mission_invites::id.asc())
+            .select(MissionInviteEntity::as_select())
+            .load::<MissionInviteEntity>(conn)?;
+        Ok(invites)
+    }
+
+    fn revoke_invite(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        invite_id: i32,
+    ) -> Result<usize> {
+        let revoked = update(mission_invites::table)
+            .filter(mission_invites::id.eq(invite_id))
+            .filter(mission_invites::mission_id.eq(mission_id))
+            .filter(invite_is_usable())
+            .set(mission_invites::revoked_at.eq(now))
+            .execute(conn)?;
+        Ok(revoked)
+    }
+
+    fn is_banned(
+        &self,
+        conn: &mut Self::Connection,
+        mission_id: i32,
+        brawler_id: i32,
+    ) -> Result<bool> {
+        let is_banned = select(exists(
+            mission_bans::table
+                .filter(mission_bans::mission_id.eq(mission_id))
+                .filter(mission_bans::brawler_id.eq(brawler_id)),
+        ))
+        .get_result::<bool>(conn)?;
+        Ok(is_banned)
+    }
+
+    fn ban(
+        &self,
+        conn: &mut Self::Connection,
+        new_mission_ban: NewMissionBan,
+    ) -> Result<()> {
+        insert_into(mission_bans::table)
+            .values(new_mission_ban)
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        Ok(())
+    }
+}
+
+// Not revoked, not expired and with uses left.
+#[diesel::dsl::auto_type]
+pub(crate) fn invite_is_usable() -> _ {
+    mission_invites::revoked_at
+        .is_null()
+        .and(
+            mission_invites::expires_at
+                .is_null()
+                .or(mission_invites::expires_at.gt(now)),
+        )
+        .and(
+            mission_invites::max_uses.is_null().or(mission_invites::uses
+                .nullable()
+                .lt(mission_invites::max_uses)),
+        )
 }
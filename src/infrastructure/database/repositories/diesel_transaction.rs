@@ -15,9 +15,11 @@ impl DieselTransaction {
 }
 
 impl TransactionProvider for DieselTransaction {
+    type Connection = PooledConnection<ConnectionManager<PgConnection>>;
+
     fn transaction<T, E, F>(&self, f: F) -> Result<T, E>
     where
-        F: FnOnce(&mut PooledConnection<ConnectionManager<PgConnection>>) -> Result<T, E> + 'static,
+        F: FnOnce(&mut Self::Connection) -> Result<T, E> + 'static,
         T: 'static,
         E: From<anyhow::Error> + From<diesel::result::Error> + 'static,
     {
@@ -1,15 +1,22 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::insert_into};
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    dsl::{delete, insert_into},
+};
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::missions::{NewMission, UpdateMission},
+        entities::{
+            mission_tags::NewMissionTag,
+            missions::{NewMission, UpdateMission},
+            tags::NewTag,
+        },
         repositories::mission_management::MissionManagementRepository,
         value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{mission_tags, missions, tags}},
 };
 
 pub struct MissionManagementPostgres {
@@ -24,27 +31,49 @@ impl MissionManagementPostgres {
 
 #[async_trait]
 impl MissionManagementRepository for MissionManagementPostgres {
-    async fn add(&self, add_mission_entity: NewMission) -> Result<i32> {
+    async fn add(&self, add_mission_entity: NewMission, tags: Vec<String>) -> Result<i32> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
-        let result = insert_into(missions::table)
-            .values(add_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut connection)?;
+        let result = connection.transaction::<_, anyhow::Error, _>(|conn| {
+            let mission_id = insert_into(missions::table)
+                .values(add_mission_entity)
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
+
+            replace_mission_tags(conn, mission_id, &tags)?;
+
+            Ok(mission_id)
+        })?;
 
         Ok(result)
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: UpdateMission) -> Result<i32> {
+    async fn edit(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        edit_mission_entity: UpdateMission,
+        tags: Option<Vec<String>>,
+    ) -> Result<i32> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut connection)?;
+        let result = connection.transaction::<_, anyhow::Error, _>(|conn| {
+            // Touching `updated_at` keeps a tags-only edit from being an empty UPDATE.
+            let mission_id = diesel::update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::chief_id.eq(chief_id))
+                .filter(missions::deleted_at.is_null())
+                .filter(missions::status.eq_any(MissionStatuses::EDITABLE))
+                .set((edit_mission_entity, missions::updated_at.eq(diesel::dsl::now)))
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
+
+            if let Some(tags) = tags {
+                replace_mission_tags(conn, mission_id, &tags)?;
+            }
+
+            Ok(mission_id)
+        })?;
 
         Ok(result)
     }
@@ -54,14 +83,45 @@ impl MissionManagementRepository for MissionManagementPostgres {
 
         diesel::update(missions::table)
             .filter(missions::id.eq(mission_id))
+            .filter(missions::chief_id.eq(chief_id))
             .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set((
-                missions::deleted_at.eq(diesel::dsl::now),
-                missions::chief_id.eq(chief_id),
-            ))
+            .filter(missions::status.eq_any(MissionStatuses::EDITABLE))
+            .set(missions::deleted_at.eq(diesel::dsl::now))
             .execute(&mut connection)?;
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Makes `tags` the mission's full tag set, creating tags that do not exist yet.
+fn replace_mission_tags(conn: &mut PgConnection, mission_id: i32, tags: &[String]) -> Result<()> {
+    delete(mission_tags::table)
+        .filter(mission_tags::mission_id.eq(mission_id))
+        .execute(conn)?;
+
+    if tags.is_empty() {
+        return Ok(());
+    }
+
+    let new_tags: Vec<NewTag> = tags.iter().map(|name| NewTag { name: name.clone() }).collect();
+    insert_into(tags::table)
+        .values(&new_tags)
+        .on_conflict(tags::name)
+        .do_nothing()
+        .execute(conn)?;
+
+    let tag_ids = tags::table
+        .filter(tags::name.eq_any(tags))
+        .select(tags::id)
+        .load::<i32>(conn)?;
+
+    let links: Vec<NewMissionTag> = tag_ids
+        .into_iter()
+        .map(|tag_id| NewMissionTag { mission_id, tag_id })
+        .collect();
+    insert_into(mission_tags::table)
+        .values(&links)
+        .execute(conn)?;
+
+    Ok(())
+}
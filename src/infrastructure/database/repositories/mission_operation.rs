@@ -1,14 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl};
+use diesel::{Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into};
 use std::sync::Arc;
 
 use crate::{
     domain::{
+        entities::{mission_status_events::NewMissionStatusEvent, missions::MissionEntity},
         repositories::mission_operation::MissionOperationRepository,
         value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{crew_memberships, mission_status_events, missions}},
 };
 
 pub struct MissionOperationPostgres {
@@ -23,42 +24,65 @@ impl MissionOperationPostgres {
 
 #[async_trait]
 impl MissionOperationRepository for MissionOperationPostgres {
-    async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn change_status(
+        &self,
+        mission_id: i32,
+        to_status: MissionStatuses,
+        actor_id: i32,
+        reason: Option<String>,
+    ) -> Result<i32> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::chief_id.eq(chief_id))
-            .set(missions::status.eq(MissionStatuses::InProgress.to_string()))
-            .returning(missions::id)
-            .get_result::<i32>(&mut connection)?;
+        let result = connection.transaction::<_, anyhow::Error, _>(|conn| {
+            // Joins and leaves lock the same row, so neither the status nor the crew can
+            // change between this check and the update.
+            let mission = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select(MissionEntity::as_select())
+                .for_update()
+                .first::<MissionEntity>(conn)?;
+            let crew_count = crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .count()
+                .first::<i64>(conn)?;
 
-        Ok(result)
-    }
+            let from_status = mission.status;
+            from_status.transition_to(to_status, mission.transition_guards(u32::try_from(crew_count)?))?;
 
-    async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+            let result = diesel::update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .set(missions::status.eq(to_status))
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
 
-        let result = diesel::update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::chief_id.eq(chief_id))
-            .set(missions::status.eq(MissionStatuses::Completed.to_string()))
-            .returning(missions::id)
-            .get_result::<i32>(&mut connection)?;
+            insert_into(mission_status_events::table)
+                .values(NewMissionStatusEvent {
+                    mission_id,
+                    from_status,
+                    to_status,
+                    actor_id,
+                    reason,
+                })
+                .execute(conn)?;
+
+            Ok(result)
+        })?;
 
         Ok(result)
     }
 
-    async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
+    async fn set_crew_role(&self, mission_id: i32, brawler_id: i32, role: String) -> Result<()> {
         let mut connection = Arc::clone(&self.db_pool).get()?;
 
-        let result = diesel::update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::chief_id.eq(chief_id))
-            .set(missions::status.eq(MissionStatuses::Failed.to_string()))
-            .returning(missions::id)
+        diesel::update(crew_memberships::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .set(crew_memberships::role.eq(role))
+            .returning(crew_memberships::brawler_id)
             .get_result::<i32>(&mut connection)?;
 
-        Ok(result)
+        Ok(())
     }
 }
+
@@ -1,9 +1,10 @@
 use std::sync::Arc;
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
+use chrono::Utc;
+use diesel::{dsl::{count, count_star, exists}, PgConnection, helper_types::{InnerJoinQuerySource, LeftJoinQuerySource}, expression::BoxableExpression, pg::Pg, sql_types::{Bool, Nullable}, BoolExpressionMethods, ExpressionMethods, AggregateExpressionMethods, NullableExpressionMethods, OptionalExtension, PgTextExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper};
 
-use crate::{domain::{entities::missions::MissionEntity, repositories::mission_viewing::MissionViewingRepository, value_objects::mission_filter::MissionFilter}, infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{crew_memberships, missions}}};
+use crate::{domain::{entities::{brawlers::BrawlerProfileEntity, crew_memberships::CrewMembershipEntity, mission_status_events::MissionStatusEventEntity, missions::MissionEntity}, repositories::mission_viewing::MissionViewingRepository, value_objects::{join_policy::JoinPolicy, mission_filter::{MissionFilter, MissionSort}, mission_statuses::MissionStatuses, venue_model::bounding_box}}, infrastructure::database::{postgresql_connection::PgPoolSquad, repositories::crew_operation::invite_is_usable, schema::{brawlers, crew_memberships, mission_invites, mission_status_events, mission_tags, missions, tags, waitlist_entries}, sql_functions::{Matches, haversine_km, mission_search_headline, mission_search_query, ts_rank}}};
 
 #[derive(Clone)]
 pub struct MissionViewingPostgres {
@@ -30,29 +31,99 @@ impl MissionViewingRepository for MissionViewingPostgres {
         Ok(result)
     }
 
-    async fn get(&self, filter: &MissionFilter) -> Result<Vec<MissionEntity>> {
+    async fn get(
+        &self,
+        filter: &MissionFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(MissionEntity, BrawlerProfileEntity, i64, Option<String>)>> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
-        let mut query = missions::table
-            .filter(missions::deleted_at.is_null())
+        let search_text = filter.search_text()?;
+        let crew_count = count(crew_memberships::brawler_id.nullable());
+        let query = mission_listing()
+            .filter(mission_filter(filter)?)
+            .group_by((missions::id, brawlers::id))
+            .select((
+                MissionEntity::as_select(),
+                BrawlerProfileEntity::as_select(),
+                crew_count,
+                mission_search_headline(
+                    missions::name,
+                    missions::description,
+                    search_text.map(str::to_string),
+                ),
+            ))
             .into_boxed();
 
-        if let Some(status) = &filter.status {
-            query = query.filter(missions::status.eq(status.to_string()));
+        // Every sort ends on the id so pages never overlap or skip rows.
+        let query = match filter.sort_by()? {
+            MissionSort::Created => query
+                .order_by(missions::created_at.desc())
+                .then_order_by(missions::id.desc()),
+            MissionSort::StartsSoon => query
+                .order_by(missions::starts_at.asc())
+                .then_order_by(missions::id.asc()),
+            MissionSort::MostCrew => query
+                .order_by(crew_count.desc())
+                .then_order_by(missions::id.desc()),
+            MissionSort::Name => query
+                .order_by(missions::name.asc())
+                .then_order_by(missions::id.asc()),
+            MissionSort::Distance => {
+                let Some(((latitude, longitude), _)) = filter.near()? else {
+                    bail!("sort=distance needs a radius search");
+                };
+                query
+                    .order_by(
+                        haversine_km(missions::latitude, missions::longitude, latitude, longitude)
+                            .asc(),
+                    )
+                    .then_order_by(missions::id.asc())
+            }
+            MissionSort::Relevance => {
+                let Some(q) = search_text else {
+                    bail!("sort=relevance needs q");
+                };
+                query
+                    .order_by(
+                        ts_rank(missions::search_vector, mission_search_query(q.to_string()))
+                            .desc(),
+                    )
+                    .then_order_by(missions::id.desc())
+            }
         };
 
-        if let Some(name) = &filter.name {
-            query = query.filter(missions::name.ilike(format!("%{}%", name)));
-        }
-
         let results = query
-            .select(MissionEntity::as_select())
-            .order_by(missions::created_at.desc())
-            .load::<MissionEntity>(&mut conn)?;
+            .limit(limit)
+            .offset(offset)
+            .load::<(MissionEntity, BrawlerProfileEntity, i64, Option<String>)>(&mut conn)?;
 
         Ok(results)
     }
 
+    async fn count(&self, filter: &MissionFilter) -> Result<i64> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let total = mission_listing()
+            .filter(mission_filter(filter)?)
+            .select(count(missions::id).aggregate_distinct())
+            .first::<i64>(&mut conn)?;
+
+        Ok(total)
+    }
+
+    async fn chief_profile(&self, chief_id: i32) -> Result<BrawlerProfileEntity> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let result = brawlers::table
+            .filter(brawlers::id.eq(chief_id))
+            .select(BrawlerProfileEntity::as_select())
+            .first::<BrawlerProfileEntity>(&mut conn)?;
+
+        Ok(result)
+    }
+
     async fn crew_counting(&self, mission_id: i32) -> Result<u32> {
         let mut conn = Arc::clone(&self.db_pool).get()?;
 
@@ -66,4 +137,215 @@ impl MissionViewingRepository for MissionViewingPostgres {
         Ok(count)
     }
 
-}
\ No newline at end of file
+    async fn crew_role(&self, mission_id: i32, brawler_id: i32) -> Result<Option<String>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let role = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .filter(crew_memberships::brawler_id.eq(brawler_id))
+            .select(crew_memberships::role)
+            .first::<String>(&mut conn)
+            .optional()?;
+
+        Ok(role)
+    }
+
+    async fn is_waitlisted(&self, mission_id: i32, brawler_id: i32) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let waiting = diesel::select(exists(
+            waitlist_entries::table
+                .filter(waitlist_entries::mission_id.eq(mission_id))
+                .filter(waitlist_entries::brawler_id.eq(brawler_id)),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(waiting)
+    }
+
+    async fn has_usable_invite(&self, mission_id: i32, token_hash: String) -> Result<bool> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let usable = diesel::select(exists(
+            mission_invites::table
+                .filter(mission_invites::mission_id.eq(mission_id))
+                .filter(mission_invites::token_hash.eq(token_hash))
+                .filter(invite_is_usable()),
+        ))
+        .get_result::<bool>(&mut conn)?;
+
+        Ok(usable)
+    }
+
+    async fn crew_roster(
+        &self,
+        mission_id: i32,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<(CrewMembershipEntity, BrawlerProfileEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = crew_memberships::table
+            .inner_join(brawlers::table)
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .order((crew_memberships::joined_at.asc(), crew_memberships::brawler_id.asc()))
+            .limit(limit)
+            .offset(offset)
+            .select((CrewMembershipEntity::as_select(), BrawlerProfileEntity::as_select()))
+            .load::<(CrewMembershipEntity, BrawlerProfileEntity)>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn status_history(
+        &self,
+        mission_id: i32,
+    ) -> Result<Vec<(MissionStatusEventEntity, BrawlerProfileEntity)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = mission_status_events::table
+            .inner_join(brawlers::table)
+            .filter(mission_status_events::mission_id.eq(mission_id))
+            .order((mission_status_events::created_at.asc(), mission_status_events::id.asc()))
+            .select((MissionStatusEventEntity::as_select(), BrawlerProfileEntity::as_select()))
+            .load::<(MissionStatusEventEntity, BrawlerProfileEntity)>(&mut conn)?;
+
+        Ok(results)
+    }
+
+    async fn mission_tags(&self, mission_ids: &[i32]) -> Result<Vec<(i32, String)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        load_mission_tags(&mut conn, mission_ids)
+    }
+
+    async fn popular_tags(&self, limit: i64) -> Result<Vec<(String, i64)>> {
+        let mut conn = Arc::clone(&self.db_pool).get()?;
+
+        let results = mission_tags::table
+            .inner_join(tags::table)
+            .inner_join(missions::table)
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::join_policy.ne(JoinPolicy::InviteOnly.to_string()))
+            .filter(missions::status.ne(MissionStatuses::Draft))
+            .group_by((tags::id, tags::name))
+            .select((tags::name, count_star()))
+            .order((count_star().desc(), tags::name.asc()))
+            .limit(limit)
+            .load::<(String, i64)>(&mut conn)?;
+
+        Ok(results)
+    }
+}
+
+/// `(mission_id, tag)` pairs for the given missions, each mission's tags in name order.
+pub(crate) fn load_mission_tags(
+    conn: &mut PgConnection,
+    mission_ids: &[i32],
+) -> Result<Vec<(i32, String)>> {
+    if mission_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let results = mission_tags::table
+        .inner_join(tags::table)
+        .filter(mission_tags::mission_id.eq_any(mission_ids))
+        .order((mission_tags::mission_id.asc(), tags::name.asc()))
+        .select((mission_tags::mission_id, tags::name))
+        .load::<(i32, String)>(conn)?;
+
+    Ok(results)
+}
+
+/// Missions with their chief and crew, the source both listing and counting read from.
+type MissionListingSource = LeftJoinQuerySource<
+    InnerJoinQuerySource<missions::table, brawlers::table>,
+    crew_memberships::table,
+>;
+
+#[diesel::dsl::auto_type]
+fn mission_listing() -> _ {
+    missions::table
+        .inner_join(brawlers::table)
+        .left_join(crew_memberships::table)
+}
+
+/// Everything in `filter` except sorting and paging, so listing and counting agree.
+fn mission_filter(
+    filter: &MissionFilter,
+) -> Result<Box<dyn BoxableExpression<MissionListingSource, Pg, SqlType = Nullable<Bool>>>> {
+    let mut predicate: Box<dyn BoxableExpression<MissionListingSource, Pg, SqlType = Nullable<Bool>>> =
+        Box::new(
+            missions::deleted_at
+                .is_null()
+                // Invite-only missions are reached through their invite, never by browsing.
+                .and(missions::join_policy.ne(JoinPolicy::InviteOnly.to_string()))
+                .and(missions::status.ne(MissionStatuses::Draft))
+                .nullable(),
+        );
+
+    if let Some(status) = &filter.status {
+        predicate = Box::new(predicate.and(missions::status.eq(*status)));
+    };
+
+    if let Some(q) = filter.search_text()? {
+        predicate = Box::new(predicate.and(Matches::new(
+            missions::search_vector,
+            mission_search_query(q.to_string()),
+        )));
+    }
+
+    if let Some(name) = &filter.name {
+        predicate = Box::new(predicate.and(missions::name.ilike(format!("%{}%", name))));
+    }
+
+    let (any_tags, all_tags) = filter.tag_filters()?;
+    if !any_tags.is_empty() {
+        predicate = Box::new(predicate.and(
+            missions::id.eq_any(
+                mission_tags::table
+                    .inner_join(tags::table)
+                    .filter(tags::name.eq_any(any_tags))
+                    .select(mission_tags::mission_id),
+            ),
+        ));
+    }
+    if !all_tags.is_empty() {
+        // Tags are unique per mission, so matching every one means matching as many rows.
+        let wanted = i64::try_from(all_tags.len())?;
+        predicate = Box::new(predicate.and(
+            missions::id.eq_any(
+                mission_tags::table
+                    .inner_join(tags::table)
+                    .filter(tags::name.eq_any(all_tags))
+                    .group_by(mission_tags::mission_id)
+                    .having(count_star().eq(wanted))
+                    .select(mission_tags::mission_id),
+            ),
+        ));
+    }
+
+    let (starts_from, starts_to) = filter.starts_between(Utc::now());
+    if let Some(starts_from) = starts_from {
+        predicate = Box::new(predicate.and(missions::starts_at.ge(starts_from.naive_utc())));
+    }
+    if let Some(starts_to) = starts_to {
+        predicate = Box::new(predicate.and(missions::starts_at.lt(starts_to.naive_utc())));
+    }
+
+    if let Some((center, radius_km)) = filter.near()? {
+        // The bounding box lets the coordinates index do the coarse cut.
+        let ((min_lat, max_lat), lng_range) = bounding_box(center, radius_km);
+        predicate = Box::new(predicate.and(missions::latitude.between(min_lat, max_lat)));
+        if let Some((min_lng, max_lng)) = lng_range {
+            predicate = Box::new(predicate.and(missions::longitude.between(min_lng, max_lng)));
+        }
+        let (latitude, longitude) = center;
+        predicate = Box::new(predicate.and(
+            haversine_km(missions::latitude, missions::longitude, latitude, longitude)
+                .le(radius_km),
+        ));
+    }
+
+    Ok(predicate)
+}
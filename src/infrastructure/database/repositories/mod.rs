@@ -1,7 +1,9 @@
 pub mod brawlers;
-pub mod crew_participation;
 pub mod mission_management;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod password_reset;
+pub mod recovery_codes;
 pub mod crew_operation;
 pub mod diesel_transaction;
+pub mod sessions;
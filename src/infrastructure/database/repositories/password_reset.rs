@@ -0,0 +1,69 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{Connection, ExpressionMethods, OptionalExtension, RunQueryDsl, dsl::{insert_into, now}};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::password_reset_tokens::NewPasswordResetToken,
+        repositories::password_reset::PasswordResetRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::{brawlers, password_reset_tokens, sessions}},
+};
+
+pub struct PasswordResetPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PasswordResetPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PasswordResetPostgres {
+    async fn create(&self, new_password_reset_token: NewPasswordResetToken) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(password_reset_tokens::table)
+            .values(new_password_reset_token)
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
+    async fn reset_password(&self, token_hash: String, hashed_password: String) -> Result<Option<i32>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = connection.transaction::<_, anyhow::Error, _>(|conn| {
+            let Some(brawler_id) = diesel::update(password_reset_tokens::table)
+                .filter(password_reset_tokens::token_hash.eq(token_hash))
+                .filter(password_reset_tokens::used_at.is_null())
+                .filter(password_reset_tokens::expires_at.gt(now))
+                .set(password_reset_tokens::used_at.eq(now))
+                .returning(password_reset_tokens::brawler_id)
+                .get_result::<i32>(conn)
+                .optional()?
+            else {
+                return Ok(None);
+            };
+
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(brawlers::password.eq(hashed_password))
+                .execute(conn)?;
+
+            // Every device has to login again with the new password.
+            diesel::update(sessions::table)
+                .filter(sessions::brawler_id.eq(brawler_id))
+                .filter(sessions::revoked_at.is_null())
+                .set(sessions::revoked_at.eq(now))
+                .execute(conn)?;
+
+            Ok(Some(brawler_id))
+        })?;
+
+        Ok(result)
+    }
+}
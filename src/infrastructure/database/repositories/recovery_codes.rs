@@ -0,0 +1,84 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper,
+    dsl::{delete, insert_into, now},
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::recovery_codes::{NewRecoveryCode, RecoveryCodeEntity},
+        repositories::recovery_codes::RecoveryCodeRepository,
+    },
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::recovery_codes},
+};
+
+pub struct RecoveryCodePostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RecoveryCodePostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RecoveryCodeRepository for RecoveryCodePostgres {
+    async fn replace_all(&self, brawler_id: i32, new_recovery_codes: Vec<NewRecoveryCode>) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        connection.transaction::<_, diesel::result::Error, _>(|conn| {
+            delete(recovery_codes::table)
+                .filter(recovery_codes::brawler_id.eq(brawler_id))
+                .execute(conn)?;
+
+            insert_into(recovery_codes::table)
+                .values(&new_recovery_codes)
+                .execute(conn)?;
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_unused(&self, brawler_id: i32) -> Result<Vec<RecoveryCodeEntity>> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = recovery_codes::table
+            .filter(recovery_codes::brawler_id.eq(brawler_id))
+            .filter(recovery_codes::used_at.is_null())
+            .select(RecoveryCodeEntity::as_select())
+            .load::<RecoveryCodeEntity>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn mark_used(&self, recovery_code_id: i32) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let updated = diesel::update(recovery_codes::table)
+            .filter(recovery_codes::id.eq(recovery_code_id))
+            .filter(recovery_codes::used_at.is_null())
+            .set(recovery_codes::used_at.eq(now))
+            .execute(&mut connection)?;
+
+        if updated == 0 {
+            return Err(anyhow::anyhow!("Recovery code has already been used"));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_all(&self, brawler_id: i32) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        delete(recovery_codes::table)
+            .filter(recovery_codes::brawler_id.eq(brawler_id))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+}
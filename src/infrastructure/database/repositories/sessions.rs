@@ -0,0 +1,71 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, dsl::{exists, insert_into, now}, select};
+use std::sync::Arc;
+
+use crate::{
+    domain::{entities::sessions::NewSession, repositories::sessions::SessionRepository},
+    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::sessions},
+};
+
+pub struct SessionPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl SessionPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for SessionPostgres {
+    async fn create(&self, new_session: NewSession) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        insert_into(sessions::table)
+            .values(new_session)
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+
+    async fn is_active(&self, jti: String) -> Result<bool> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let result = select(exists(
+            sessions::table
+                .filter(sessions::jti.eq(jti))
+                .filter(sessions::revoked_at.is_null())
+                .filter(sessions::expires_at.gt(now)),
+        ))
+        .get_result::<bool>(&mut connection)?;
+
+        Ok(result)
+    }
+
+    async fn revoke(&self, jti: String) -> Result<usize> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        let revoked = diesel::update(sessions::table)
+            .filter(sessions::jti.eq(jti))
+            .filter(sessions::revoked_at.is_null())
+            .filter(sessions::expires_at.gt(now))
+            .set(sessions::revoked_at.eq(now))
+            .execute(&mut connection)?;
+
+        Ok(revoked)
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()> {
+        let mut connection = Arc::clone(&self.db_pool).get()?;
+
+        diesel::update(sessions::table)
+            .filter(sessions::brawler_id.eq(brawler_id))
+            .filter(sessions::revoked_at.is_null())
+            .set(sessions::revoked_at.eq(now))
+            .execute(&mut connection)?;
+
+        Ok(())
+    }
+}
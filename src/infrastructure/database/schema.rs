@@ -1,5 +1,15 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "mission_status"))]
+    pub struct MissionStatus;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -9,6 +19,19 @@ diesel::table! {
         password -> Varchar,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        #[max_length = 255]
+        email -> Nullable<Varchar>,
+        #[max_length = 64]
+        totp_secret -> Nullable<Varchar>,
+        totp_enabled -> Bool,
+        #[max_length = 50]
+        display_name -> Varchar,
+        avatar_url -> Nullable<Text>,
+        #[max_length = 255]
+        avatar_public_id -> Nullable<Varchar>,
+        totp_failed_attempts -> Int4,
+        totp_locked_until -> Nullable<Timestamp>,
+        totp_last_step -> Nullable<Int8>,
     }
 }
 
@@ -17,26 +40,184 @@ diesel::table! {
         mission_id -> Int4,
         brawler_id -> Int4,
         joined_at -> Timestamp,
+        #[max_length = 20]
+        role -> Varchar,
+    }
+}
+
+diesel::table! {
+    join_requests (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 20]
+        status -> Varchar,
+        created_at -> Timestamp,
+        decided_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    mission_bans (mission_id, brawler_id) {
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        banned_by -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_invites (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        created_by -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Nullable<Timestamp>,
+        max_uses -> Nullable<Int4>,
+        uses -> Int4,
+        revoked_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MissionStatus;
+
+    mission_status_events (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        from_status -> MissionStatus,
+        to_status -> MissionStatus,
+        actor_id -> Int4,
+        reason -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    mission_tags (mission_id, tag_id) {
+        mission_id -> Int4,
+        tag_id -> Int4,
     }
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::{MissionStatus, Tsvector};
+
     missions (id) {
         id -> Int4,
         #[max_length = 255]
         name -> Varchar,
         description -> Nullable<Text>,
-        #[max_length = 255]
-        status -> Varchar,
+        status -> MissionStatus,
         chief_id -> Int4,
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        starts_at -> Timestamp,
+        ends_at -> Timestamp,
+        #[max_length = 64]
+        timezone -> Varchar,
+        #[max_length = 255]
+        venue_name -> Nullable<Varchar>,
+        venue_address -> Nullable<Text>,
+        latitude -> Nullable<Float8>,
+        longitude -> Nullable<Float8>,
+        max_crew -> Int4,
+        min_crew -> Int4,
+        #[max_length = 20]
+        join_policy -> Varchar,
+        search_vector -> Tsvector,
+    }
+}
+
+diesel::table! {
+    password_reset_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        used_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    recovery_codes (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        #[max_length = 255]
+        code_hash -> Varchar,
+        created_at -> Timestamp,
+        used_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    sessions (jti) {
+        #[max_length = 64]
+        jti -> Varchar,
+        brawler_id -> Int4,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    tags (id) {
+        id -> Int4,
+        #[max_length = 50]
+        name -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    waitlist_entries (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        brawler_id -> Int4,
+        joined_at -> Timestamp,
     }
 }
 
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(join_requests -> brawlers (brawler_id));
+diesel::joinable!(join_requests -> missions (mission_id));
+diesel::joinable!(mission_bans -> missions (mission_id));
+diesel::joinable!(mission_invites -> brawlers (created_by));
+diesel::joinable!(mission_invites -> missions (mission_id));
+diesel::joinable!(mission_status_events -> brawlers (actor_id));
+diesel::joinable!(mission_status_events -> missions (mission_id));
+diesel::joinable!(mission_tags -> missions (mission_id));
+diesel::joinable!(mission_tags -> tags (tag_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(password_reset_tokens -> brawlers (brawler_id));
+diesel::joinable!(recovery_codes -> brawlers (brawler_id));
+diesel::joinable!(sessions -> brawlers (brawler_id));
+diesel::joinable!(waitlist_entries -> brawlers (brawler_id));
+diesel::joinable!(waitlist_entries -> missions (mission_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    brawlers,
+    crew_memberships,
+    join_requests,
+    mission_bans,
+    mission_invites,
+    mission_status_events,
+    mission_tags,
+    missions,
+    password_reset_tokens,
+    recovery_codes,
+    sessions,
+    tags,
+    waitlist_entries,
+);
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, missions,);
+// Lets mission listings group by `(missions.id, brawlers.id)` to count crews in one query.
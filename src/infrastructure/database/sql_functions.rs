@@ -0,0 +1,39 @@
+use diesel::{
+    declare_sql_function, infix_operator,
+    pg::Pg,
+    sql_types::{Double, Nullable, SqlType, Text},
+};
+
+use crate::infrastructure::database::schema::sql_types::Tsvector;
+
+#[derive(diesel::query_builder::QueryId, Clone, SqlType)]
+#[diesel(postgres_type(name = "tsquery", schema = "pg_catalog"))]
+pub struct Tsquery;
+
+// `tsvector @@ tsquery`
+infix_operator!(Matches, " @@ ", backend: Pg);
+
+#[declare_sql_function]
+extern "SQL" {
+    /// Great-circle distance in kilometres, see the `haversine_km` migration.
+    /// `NULL` when the mission has no coordinates.
+    fn haversine_km(
+        lat1: Nullable<Double>,
+        lng1: Nullable<Double>,
+        lat2: Double,
+        lng2: Double,
+    ) -> Nullable<Double>;
+
+    /// Parses free text (quotes, `or`, `-`) into a query against `missions.search_vector`.
+    fn mission_search_query(q: Text) -> Tsquery;
+
+    /// The HTML-escaped name and description with the terms matching `q` wrapped
+    /// in `<b>`, or `NULL` when there is no `q`.
+    fn mission_search_headline(
+        name: Text,
+        description: Nullable<Text>,
+        q: Nullable<Text>,
+    ) -> Nullable<Text>;
+
+    fn ts_rank(vector: Tsvector, query: Tsquery) -> Float;
+}
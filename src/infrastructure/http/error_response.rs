@@ -1,12 +1,48 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use serde::{Deserialize, Serialize};
+use tracing::error;
+
+use crate::domain::app_error::AppError;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
+    pub code: String,
     pub message: String,
 }
 
 impl ErrorResponse {
-    pub fn new(message: String) -> Self {
-        Self { message }
+    pub fn new(code: &str, message: String) -> Self {
+        Self {
+            code: code.to_string(),
+            message,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::Validation(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        // Internal details go to the log, never to the client.
+        let message = match &self {
+            AppError::Internal(e) => {
+                error!("Internal error: {:?}", e);
+                "Internal server error".to_string()
+            }
+            _ => self.to_string(),
+        };
+
+        (status, Json(ErrorResponse::new(self.code(), message))).into_response()
     }
 }
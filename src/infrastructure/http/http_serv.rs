@@ -20,6 +20,7 @@ use tracing::info;
 
 use crate::{
     config::config_model::DotEnvyConfig,
+    domain::app_error::AppError,
     infrastructure::{
         database::postgresql_connection::PgPoolSquad,
         http::routers,
@@ -34,15 +35,17 @@ fn static_serve() -> Router {
     Router::new().fallback_service(service)
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
-    Router::new()
+fn api_serve(db_pool: Arc<PgPoolSquad>) -> Result<Router> {
+    let router = Router::new()
         .nest(
             "/authentication",
-            routers::authentication::routes(Arc::clone(&db_pool)),
+            routers::authentication::routes(Arc::clone(&db_pool))
+                .merge(routers::password::routes(Arc::clone(&db_pool))?)
+                .merge(routers::two_factor::routes(Arc::clone(&db_pool))),
         )
         .nest(
             "/brawler",
-            routers::brawlers::routes(Arc::clone(&db_pool)),
+            routers::brawlers::routes(Arc::clone(&db_pool))?,
         )
         .nest(
             "/crew",
@@ -58,13 +61,16 @@ fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
             "/util",
             routers::default::routes(),
         )
-        .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+        .fallback(|| async { AppError::NotFound("API not found".to_string()) });
+
+    Ok(router)
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
     let app = Router::new()
         .merge(static_serve())
-        .nest("/api", api_serve(Arc::clone(&db_pool)))
+        .merge(routers::well_known::routes())
+        .nest("/api", api_serve(Arc::clone(&db_pool))?)
         // .fallback(default_router::health_check)
         // .route("/health_check", get(default_router::health_check)
         .layer(TimeoutLayer::with_status_code(
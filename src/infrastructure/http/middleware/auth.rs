@@ -1,35 +1,96 @@
+use std::sync::Arc;
+
 use axum::{
-    extract::Request,
-    http::{StatusCode, header},
+    extract::{Request, State},
+    http::header,
     middleware::Next,
-    response::{Response, IntoResponse},
+    response::Response,
 };
 
-use crate::config::config_loader::get_jwt_env;
+use crate::{
+    domain::{app_error::AppError, repositories::sessions::SessionRepository},
+    infrastructure::{
+        database::{postgresql_connection::PgPoolSquad, repositories::sessions::SessionPostgres},
+        jwt::{
+            access_keys,
+            jwt_model::{Claims, TokenType},
+        },
+    },
+};
 
-pub async fn authorization(mut req: Request, next: Next) -> Result<Response, Response> {
+pub async fn authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
     let auth_header = req
         .headers()
         .get(header::AUTHORIZATION)
         .and_then(|value| value.to_str().ok())
-        .ok_or_else(|| (StatusCode::UNAUTHORIZED, "Missing or invalid Authorization header").into_response())?;
+        .ok_or_else(|| unauthorized("Missing or invalid Authorization header"))?;
+
+    let claims = verify_access_token(db_pool, auth_header).await?;
+    insert_claims(&mut req, claims)?;
+
+    Ok(next.run(req).await)
+}
+
+/// Like `authorization`, but lets requests without an Authorization header through
+/// anonymously. A header that is present still has to hold a valid access token.
+pub async fn optional_authorization(
+    State(db_pool): State<Arc<PgPoolSquad>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let Some(auth_header) = req.headers().get(header::AUTHORIZATION) else {
+        return Ok(next.run(req).await);
+    };
+    let auth_header = auth_header
+        .to_str()
+        .map_err(|_| unauthorized("Missing or invalid Authorization header"))?;
+
+    let claims = verify_access_token(db_pool, auth_header).await?;
+    insert_claims(&mut req, claims)?;
+
+    Ok(next.run(req).await)
+}
 
+async fn verify_access_token(db_pool: Arc<PgPoolSquad>, auth_header: &str) -> Result<Claims, AppError> {
     let token = auth_header
         .strip_prefix("Bearer ")
-        .ok_or_else(|| (StatusCode::UNAUTHORIZED, "Invalid Bearer token format").into_response())?;
+        .ok_or_else(|| unauthorized("Invalid Bearer token format"))?;
+
+    let access_keys = access_keys()?;
 
-    let secret_env = get_jwt_env()
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, format!("Failed to get JWT secret: {}", e)).into_response())?;
+    let claims = crate::infrastructure::jwt::verify_token(access_keys, token.to_string())
+        .map_err(|e| AppError::Unauthorized(format!("Token verification failed: {}", e)))?;
 
-    let claims = crate::infrastructure::jwt::verify_token(secret_env, token.to_string())
-        .map_err(|e| (StatusCode::UNAUTHORIZED, format!("Token verification failed: {}", e)).into_response())?;
+    if claims.typ != TokenType::Access {
+        return Err(unauthorized("Invalid token type"));
+    }
 
+    let session_active = SessionPostgres::new(db_pool)
+        .is_active(claims.jti.clone())
+        .await?;
+    if !session_active {
+        return Err(unauthorized("Session has been revoked"));
+    }
+
+    Ok(claims)
+}
+
+fn insert_claims(req: &mut Request, claims: Claims) -> Result<(), AppError> {
     let brawler_id = claims
         .sub
         .parse::<i32>()
-        .map_err(|e| (StatusCode::UNAUTHORIZED, format!("Invalid subject in token: {}", e)).into_response())?;
+        .map_err(|e| AppError::Unauthorized(format!("Invalid subject in token: {}", e)))?;
 
     req.extensions_mut().insert(brawler_id);
+    req.extensions_mut().insert(claims);
 
-    Ok(next.run(req).await)
+    Ok(())
+}
+
+fn unauthorized(message: &str) -> AppError {
+    AppError::Unauthorized(message.to_string())
 }
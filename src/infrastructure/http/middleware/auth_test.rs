@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{Router, http::StatusCode, middleware, routing::get};
+    use chrono::{Duration, Utc};
+    use diesel::{
+        PgConnection,
+        r2d2::{ConnectionManager, Pool},
+    };
+
+    use crate::{
+        application::use_cases::authentication::AuthenticationUseCase,
+        domain::{
+            entities::brawlers::BrawlerEntity,
+            repositories::{
+                brawlers::MockBrawlerRepository, recovery_codes::MockRecoveryCodeRepository,
+                sessions::MockSessionRepository,
+            },
+        },
+        infrastructure::{
+            argon2::hash,
+            http::middleware::auth::authorization,
+            jwt::{
+                access_keys,
+                authentication_model::LoginModel,
+                generate_token,
+                jwt_model::{Claims, LoginOutcome, TokenType},
+            },
+            totp,
+        },
+    };
+
+    fn two_factor_brawler() -> BrawlerEntity {
+        let now = Utc::now().naive_utc();
+        BrawlerEntity {
+            id: 1,
+            username: "menta".to_string(),
+            password: hash("P@ssw0rd".to_string()).unwrap(),
+            created_at: now,
+            updated_at: now,
+            email: None,
+            totp_secret: Some(totp::generate_secret()),
+            totp_enabled: true,
+            display_name: "Menta".to_string(),
+            avatar_url: None,
+            avatar_public_id: None,
+            totp_failed_attempts: 0,
+            totp_locked_until: None,
+            totp_last_step: None,
+        }
+    }
+
+    // Serves one route behind the middleware. The pool never connects, so a request
+    // that got as far as the session lookup would fail with a 500 instead of a 401.
+    async fn protected_server() -> String {
+        let db_pool = Pool::builder()
+            .build_unchecked(ConnectionManager::<PgConnection>::new("postgres://unreachable"));
+        let router = Router::new()
+            .route("/protected", get(|| async { "ok" }))
+            .route_layer(middleware::from_fn_with_state(Arc::new(db_pool), authorization));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        format!("http://{}/protected", address)
+    }
+
+    async fn status_with_token(url: &str, token: &str) -> StatusCode {
+        let response = reqwest::Client::new()
+            .get(url)
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap();
+        StatusCode::from_u16(response.status().as_u16()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_authorization_refuses_mfa_pending_token() {
+        dotenvy::dotenv().ok();
+        let mut mock_brawler_repository = MockBrawlerRepository::new();
+        mock_brawler_repository
+            .expect_find_by_username()
+            .returning(|_| Box::pin(async { Ok(two_factor_brawler()) }));
+
+        let use_case = AuthenticationUseCase::new(
+            Arc::new(mock_brawler_repository),
+            Arc::new(MockSessionRepository::new()),
+            Arc::new(MockRecoveryCodeRepository::new()),
+        );
+        let LoginOutcome::MfaPending(challenge) = use_case
+            .login(LoginModel {
+                username: "menta".to_string(),
+                password: "P@ssw0rd".to_string(),
+            })
+            .await
+            .unwrap()
+        else {
+            panic!("expected an MFA challenge");
+        };
+
+        let url = protected_server().await;
+
+        assert_eq!(
+            status_with_token(&url, &challenge.mfa_token).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authorization_refuses_mfa_pending_type_signed_with_access_key() {
+        dotenvy::dotenv().ok();
+        let token = generate_token(
+            access_keys().unwrap(),
+            &Claims {
+                sub: "1".to_string(),
+                jti: "mfa-1".to_string(),
+                typ: TokenType::MfaPending,
+                exp: (Utc::now() + Duration::minutes(5)).timestamp() as usize,
+                iat: Utc::now().timestamp() as usize,
+            },
+        )
+        .unwrap();
+
+        let url = protected_server().await;
+
+        assert_eq!(
+            status_with_token(&url, &token).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
+}
@@ -1 +1,4 @@
-pub mod auth;
\ No newline at end of file
+pub mod auth;
+
+#[cfg(test)]
+pub(crate) mod auth_test;
@@ -1,3 +1,4 @@
+pub mod error_response;
 pub mod http_serv;
 pub mod routers;
 pub mod middleware;
@@ -1,135 +1,236 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::State,
     http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware,
     response::IntoResponse,
     routing::post,
 };
-use axum_extra::extract::cookie::{Cookie};
+use axum_extra::extract::cookie::{Cookie, CookieJar};
 use cookie::time::Duration;
 
 use crate::{
     application::use_cases::authentication::AuthenticationUseCase,
     config::{config_loader::get_stage, stage::Stage},
-    domain::repositories::brawlers::BrawlerRepository,
+    domain::{
+        app_error::AppError,
+        repositories::{
+            brawlers::BrawlerRepository, recovery_codes::RecoveryCodeRepository,
+            sessions::SessionRepository,
+        },
+    },
     infrastructure::{
-        database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres},
-        jwt::authentication_model::LoginModel,
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, recovery_codes::RecoveryCodePostgres,
+                sessions::SessionPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+        jwt::{
+            authentication_model::{LoginModel, MfaLoginModel},
+            jwt_model::{Claims, LoginOutcome, Passport},
+        },
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
     let brawlers_repository = BrawlerPostgres::new(Arc::clone(&db_pool));
-    let authentication_use_case = AuthenticationUseCase::new(Arc::new(brawlers_repository));
+    let session_repository = SessionPostgres::new(Arc::clone(&db_pool));
+    let recovery_code_repository = RecoveryCodePostgres::new(Arc::clone(&db_pool));
+    let authentication_use_case = Arc::new(AuthenticationUseCase::new(
+        Arc::new(brawlers_repository),
+        Arc::new(session_repository),
+        Arc::new(recovery_code_repository),
+    ));
+
+    let protected_router = Router::new()
+        .route("/logout", post(logout))
+        .route("/logout-all", post(logout_all))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
+        .with_state(Arc::clone(&authentication_use_case));
 
     Router::new()
         .route("/login", post(login))
-        // .route("/refresh-token", post(refresh_token))
-        .with_state(Arc::new(authentication_use_case))
+        .route("/login/mfa", post(login_mfa))
+        .route("/refresh-token", post(refresh_token))
+        .with_state(authentication_use_case)
+        .merge(protected_router)
 }
 
-pub async fn login<T>(
-    State(authentication_use_case): State<Arc<AuthenticationUseCase<T>>>,
+pub async fn login<T1, T2, T3>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
 {
     match authentication_use_case.login(login_model).await {
-        Ok(passport) => {
-            let mut token = Cookie::build(("token", passport.access_token.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(7));
-
-            let mut refresh_token = Cookie::build(("refresh_token", passport.token_type.clone()))
-                .path("/")
-                .same_site(cookie::SameSite::Lax)
-                .http_only(true)
-                .max_age(Duration::days(7));
-
-            if get_stage() == Stage::Production {
-                refresh_token = refresh_token.secure(true);
-                token = token.secure(true);
-            }
-
-            let mut headers = HeaderMap::new();
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&token.to_string()).unwrap(),
-            );
-            headers.append(
-                header::SET_COOKIE,
-                HeaderValue::from_str(&refresh_token.to_string()).unwrap(),
-            );
-
-            (
-                StatusCode::OK,
-                headers,
-                Json(serde_json::json!({
-                    "access_token": passport.access_token,
-                    "token_type": passport.token_type,
-                    "expires_in": passport.expires_in,
-                    "display_name": passport.display_name,
-                    "avatar_url": passport.avatar_url,
-                    "message": "Login successfully"
-                })),
-            )
-                .into_response()
-        }
-        Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
+        Ok(LoginOutcome::MfaPending(challenge)) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "mfa_required": true,
+                "mfa_token": challenge.mfa_token,
+                "expires_in": challenge.expires_in,
+                "message": "Two-factor code required"
+            })),
+        )
+            .into_response(),
+        Ok(LoginOutcome::Passport(passport)) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            Json(serde_json::json!({
+                "access_token": passport.access_token,
+                "token_type": passport.token_type,
+                "expires_in": passport.expires_in,
+                "display_name": passport.display_name,
+                "avatar_url": passport.avatar_url,
+                "message": "Login successfully"
+            })),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn login_mfa<T1, T2, T3>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    Json(mfa_login_model): Json<MfaLoginModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
+{
+    match authentication_use_case.login_mfa(mfa_login_model).await {
+        Ok(passport) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            Json(serde_json::json!({
+                "access_token": passport.access_token,
+                "token_type": passport.token_type,
+                "expires_in": passport.expires_in,
+                "display_name": passport.display_name,
+                "avatar_url": passport.avatar_url,
+                "message": "Login successfully"
+            })),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn refresh_token<T1, T2, T3>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    jar: CookieJar,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
+{
+    let Some(rft) = jar.get("refresh_token") else {
+        return AppError::Unauthorized("Refresh token not found".to_string()).into_response();
+    };
+
+    match authentication_use_case
+        .refresh_token(rft.value().to_string())
+        .await
+    {
+        Ok(passport) => (
+            StatusCode::OK,
+            passport_cookies(&passport),
+            Json(serde_json::json!({
+                "access_token": passport.access_token,
+                "token_type": passport.token_type,
+                "expires_in": passport.expires_in,
+                "display_name": passport.display_name,
+                "avatar_url": passport.avatar_url,
+                "message": "Refresh token successfully"
+            })),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn logout<T1, T2, T3>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    Extension(claims): Extension<Claims>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
+{
+    match authentication_use_case.logout(claims.jti).await {
+        Ok(_) => (StatusCode::OK, removal_cookies(), "Logout successfully").into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn logout_all<T1, T2, T3>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2, T3>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
+{
+    match authentication_use_case.logout_all(brawler_id).await {
+        Ok(_) => (StatusCode::OK, removal_cookies(), "Logout from every device successfully").into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+fn passport_cookies(passport: &Passport) -> HeaderMap {
+    let mut token = Cookie::build(("token", passport.access_token.clone()))
+        .path("/")
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .max_age(Duration::days(7));
+
+    let mut refresh_token = Cookie::build(("refresh_token", passport.refresh_token.clone()))
+        .path("/")
+        .same_site(cookie::SameSite::Lax)
+        .http_only(true)
+        .max_age(Duration::days(7));
+
+    if get_stage() == Stage::Production {
+        refresh_token = refresh_token.secure(true);
+        token = token.secure(true);
     }
+
+    let mut headers = HeaderMap::new();
+    headers.append(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&token.to_string()).unwrap(),
+    );
+    headers.append(
+        header::SET_COOKIE,
+        HeaderValue::from_str(&refresh_token.to_string()).unwrap(),
+    );
+
+    headers
 }
 
-// pub async fn refresh_token<T>(
-//     State(authentication_use_case): State<Arc<AuthenticationUseCase<T>>>,
-//     jar: CookieJar,
-// ) -> impl IntoResponse
-// where
-//     T: BrawlerRepository + Send + Sync,
-// {
-//     if let Some(rft) = jar.get("refresh_token") {
-//         let refresh_token = rft.value().to_string();
-
-//         let response = match authentication_use_case.refresh_token(refresh_token).await {
-//             Ok(passport) => {
-//                 let mut token = Cookie::build(("token", passport.access_token.clone()))
-//                     .path("/")
-//                     .same_site(cookie::SameSite::Lax)
-//                     .http_only(true)
-//                     .max_age(Duration::days(7));
-
-//                 let mut refresh_token =
-//                     Cookie::build(("refresh_token", passport.token_type.clone()))
-//                         .path("/")
-//                         .same_site(cookie::SameSite::Lax)
-//                         .http_only(true)
-//                         .max_age(Duration::days(7));
-
-//                 if get_stage() == Stage::Production {
-//                     token = token.secure(true);
-//                     refresh_token = refresh_token.secure(true);
-//                 }
-
-//                 let mut headers = HeaderMap::new();
-//                 headers.append(
-//                     header::SET_COOKIE,
-//                     HeaderValue::from_str(&token.to_string()).unwrap(),
-//                 );
-//                 headers.append(
-//                     header::SET_COOKIE,
-//                     HeaderValue::from_str(&refresh_token.to_string()).unwrap(),
-//                 );
-
-//                 (StatusCode::OK, headers, "Refresh token successfully").into_response()
-//             }
-//             Err(e) => (StatusCode::UNAUTHORIZED, e.to_string()).into_response(),
-//         };
-//         return response;
-//     }
-
-//     (StatusCode::BAD_REQUEST, "Refresh token not found").into_response()
-// }
+fn removal_cookies() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for name in ["token", "refresh_token"] {
+        let cookie = Cookie::build((name, ""))
+            .path("/")
+            .max_age(Duration::ZERO);
+        headers.append(
+            header::SET_COOKIE,
+            HeaderValue::from_str(&cookie.to_string()).unwrap(),
+        );
+    }
+
+    headers
+}
@@ -1,41 +1,53 @@
 use std::sync::Arc;
 
-use axum::{ Extension, Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::{get, post}};
+use anyhow::Result;
+use axum::{ Extension, Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::{get, patch, post}};
 
 use crate::{
     application::use_cases::{brawlers::BrawlersUseCase, authentication::AuthenticationUseCase},
+    config::config_loader::get_image_storage_env,
     domain::{
-        repositories::brawlers::BrawlerRepository,
-        value_objects::{brawler_model::RegisterBrawlerModel, uploaded_image::UploadedAvartar},
+        repositories::{brawlers::BrawlerRepository, image_storage::ImageStorage, recovery_codes::RecoveryCodeRepository, sessions::SessionRepository},
+        value_objects::{brawler_model::{RegisterBrawlerModel, UpdateEmailModel}, uploaded_image::UploadedAvartar},
     },
     infrastructure::{database::{
-        postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres,
-    }, http::middleware::auth::authorization, jwt::authentication_model::LoginModel},
+        postgresql_connection::PgPoolSquad, repositories::{brawlers::BrawlerPostgres, recovery_codes::RecoveryCodePostgres, sessions::SessionPostgres},
+    }, http::middleware::auth::authorization, image_storage, jwt::{authentication_model::LoginModel, jwt_model::LoginOutcome}},
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let brawlers_repository = Arc::new(BrawlerPostgres::new(db_pool));
-    let brawlers_use_case = Arc::new(BrawlersUseCase::new(Arc::clone(&brawlers_repository)));
-    let authentication_use_case = Arc::new(AuthenticationUseCase::new(Arc::clone(&brawlers_repository)));
+type RegisterState<T1, T2, T3, T4> = (Arc<BrawlersUseCase<T1, T4>>, Arc<AuthenticationUseCase<T1, T2, T3>>);
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Result<Router> {
+    let brawlers_repository = Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool)));
+    let session_repository = Arc::new(SessionPostgres::new(Arc::clone(&db_pool)));
+    let image_storage_env = get_image_storage_env()?;
+    let image_storage = image_storage::from_env(&image_storage_env)?;
+    let brawlers_use_case = Arc::new(BrawlersUseCase::new(Arc::clone(&brawlers_repository), image_storage));
+    let recovery_code_repository = Arc::new(RecoveryCodePostgres::new(Arc::clone(&db_pool)));
+    let authentication_use_case = Arc::new(AuthenticationUseCase::new(Arc::clone(&brawlers_repository), session_repository, recovery_code_repository));
 
     let protected_router = Router::new()
+        .route("/email", patch(update_email))
         .route("/avatar", post(upload_avatar))
         .route("/my-missions", get(get_missions))
-        .route_layer(axum::middleware::from_fn(authorization))
+        .route_layer(axum::middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
         .with_state(brawlers_use_case.clone());
 
-    Router::new()
+    Ok(Router::new()
         .route("/register", post(register))
         .with_state((brawlers_use_case, authentication_use_case))
-        .merge(protected_router)
+        .merge(protected_router))
 }
 
-pub async fn register<T>(
-    State((brawlers_use_case, authentication_use_case)): State<(Arc<BrawlersUseCase<T>>, Arc<AuthenticationUseCase<T>>)>,
+pub async fn register<T1, T2, T3, T4>(
+    State((brawlers_use_case, authentication_use_case)): State<RegisterState<T1, T2, T3, T4>>,
     Json(register_brawler_model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: SessionRepository + Send + Sync,
+    T3: RecoveryCodeRepository + Send + Sync,
+    T4: ImageStorage + Send + Sync + ?Sized,
 {
     let username = register_brawler_model.username.clone();
     let password = register_brawler_model.password.clone();
@@ -44,7 +56,7 @@ where
         Ok(_) => {
             let login_model = LoginModel { username, password };
             match authentication_use_case.login(login_model).await {
-                Ok(passport) => (
+                Ok(LoginOutcome::Passport(passport)) => (
                     StatusCode::CREATED, 
                     Json(serde_json::json!({
                         "access_token": passport.access_token,
@@ -55,48 +67,58 @@ where
                         "message": "Register and Login successfully"
                     }))
                 ).into_response(),
-                Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json(serde_json::json!({"message": e.to_string()}))).into_response(),
+                // A brand new brawler cannot have 2FA enabled yet.
+                Ok(LoginOutcome::MfaPending(_)) => (StatusCode::CREATED, Json(serde_json::json!({"message": "Register successfully, please login"}))).into_response(),
+                Err(e) => e.into_response(),
             }
         },
-        Err(e) => {
-            let error_message = e.to_string();
-            let status = if error_message.contains("Username already exists") {
-                StatusCode::CONFLICT
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, Json(serde_json::json!({ "message": error_message }))).into_response()
-        },
+        Err(e) => e.into_response(),
     }
 }
 
+pub async fn update_email<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(update_email_model): Json<UpdateEmailModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: ImageStorage + Send + Sync + ?Sized,
+{
+    match brawlers_use_case.update_email(brawler_id, update_email_model).await {
+        Ok(_) => (StatusCode::OK, "Email updated").into_response(),
+        Err(e) => e.into_response(),
+    }
+}
 
-pub async fn upload_avatar<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn upload_avatar<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
     Extension(brawler_id): Extension<i32>,
     Json(upload_image): Json<UploadedAvartar>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: ImageStorage + Send + Sync + ?Sized,
   {
     match brawlers_use_case
         .upload_avatar(upload_image.base64_string, brawler_id)
         .await
     {
         Ok(uploaded_image) => (StatusCode::CREATED, Json(serde_json::json!({ "url": uploaded_image }))).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_missions<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn get_missions<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
     Extension(brawler_id): Extension<i32>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: ImageStorage + Send + Sync + ?Sized,
 {
     match brawlers_use_case.get_my_missions(brawler_id).await {
         Ok(missions) => (StatusCode::OK, Json(missions)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, Json::<serde_json::Value>(serde_json::json!({"message": e.to_string()}))).into_response(),
+        Err(e) => e.into_response(),
     }
 }
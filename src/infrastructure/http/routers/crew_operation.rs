@@ -1,26 +1,31 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
-    extract::{Path, State},
+    Extension, Json, Router,
+    extract::{Path, Query, State},
     http::StatusCode,
     middleware,
-    response::IntoResponse,
-    routing::{delete, post},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
 };
 
 use crate::{
     application::use_cases::crew_operation::CrewOperationUseCase,
-    domain::repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-        transaction_provider::TransactionProvider,
+    domain::{
+        repositories::{
+            crew_operation::CrewOperationRepository, transaction_provider::TransactionProvider,
+        },
+        value_objects::{
+            crew_model::KickCrewMemberQuery,
+            mission_invite_model::{NewMissionInviteModel, RedeemMissionInviteModel},
+            waitlist_model::CrewJoinOutcome,
+        },
     },
     infrastructure::{
         database::{
             postgresql_connection::PgPoolSquad,
             repositories::{
-                crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres,
-                diesel_transaction::DieselTransaction,
+                crew_operation::CrewOperationPostgres, diesel_transaction::DieselTransaction,
             },
         },
         http::middleware::auth::authorization,
@@ -28,35 +33,58 @@ use crate::{
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let crew_operation_repository = CrewOperationPostgres::new();
     let diesel_transaction = DieselTransaction::new(Arc::clone(&db_pool));
 
     let use_case = CrewOperationUseCase::new(
         Arc::new(crew_operation_repository),
-        Arc::new(mission_viewing_repository),
         Arc::new(diesel_transaction),
     );
 
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
-        .route_layer(middleware::from_fn(authorization))
+        .route("/kick/{mission_id}/{brawler_id}", delete(kick))
+        .route("/waitlist/{mission_id}", get(waitlist_position))
+        .route("/waitlist/{mission_id}", delete(leave_waitlist))
+        .route("/requests/{mission_id}", get(join_requests))
+        .route(
+            "/requests/{mission_id}/{request_id}/approve",
+            post(approve_join_request),
+        )
+        .route(
+            "/requests/{mission_id}/{request_id}/reject",
+            post(reject_join_request),
+        )
+        .route("/invites/redeem", post(redeem_invite))
+        .route("/invites/{mission_id}", post(create_invite))
+        .route("/invites/{mission_id}", get(invites))
+        .route("/invites/{mission_id}/{invite_id}", delete(revoke_invite))
+        .route_layer(middleware::from_fn_with_state(
+            Arc::clone(&db_pool),
+            authorization,
+        ))
         .with_state(Arc::new(use_case))
 }
 
-pub async fn join<T1, T2, T3>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+pub async fn join<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: TransactionProvider + Send + Sync,
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
 {
     match crew_operation_use_case.join(mission_id, brawler_id).await {
-        Ok(_) => (
+        Ok(outcome) => join_outcome_response(mission_id, brawler_id, outcome),
+        Err(e) => e.into_response(),
+    }
+}
+
+fn join_outcome_response(mission_id: i32, brawler_id: i32, outcome: CrewJoinOutcome) -> Response {
+    match outcome {
+        CrewJoinOutcome::Joined => (
             StatusCode::OK,
             format!(
                 "Brawler id: {}, has joined mission id: {}",
@@ -64,19 +92,33 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        CrewJoinOutcome::Waitlisted { position } => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Mission id: {} is full, brawler id: {} is number {} on the waitlist",
+                mission_id, brawler_id, position
+            ),
+        )
+            .into_response(),
+        CrewJoinOutcome::Requested => (
+            StatusCode::ACCEPTED,
+            format!(
+                "Brawler id: {}, has asked the chief to join mission id: {}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
     }
 }
 
-pub async fn leave<T1, T2, T3>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2, T3>>>,
+pub async fn leave<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
     Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
-    T3: TransactionProvider + Send + Sync,
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
 {
     match crew_operation_use_case.leave(mission_id, brawler_id).await {
         Ok(_) => (
@@ -87,6 +129,239 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn kick<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    Query(kick_query): Query<KickCrewMemberQuery>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .kick(mission_id, brawler_id, chief_id, kick_query.ban)
+        .await
+    {
+        Ok(_) if kick_query.ban => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {}, has been removed and banned from mission id: {}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {}, has been removed from mission id: {}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn waitlist_position<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .waitlist_position(mission_id, brawler_id)
+        .await
+    {
+        Ok(waitlist_position) => (StatusCode::OK, Json(waitlist_position)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn leave_waitlist<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .leave_waitlist(mission_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {}, has left the waitlist of mission id: {}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn join_requests<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .join_requests(mission_id, brawler_id)
+        .await
+    {
+        Ok(join_requests) => (StatusCode::OK, Json(join_requests)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn approve_join_request<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path((mission_id, request_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .approve_join_request(mission_id, request_id, brawler_id)
+        .await
+    {
+        Ok(CrewJoinOutcome::Waitlisted { position }) => (
+            StatusCode::OK,
+            format!(
+                "Join request id: {} has been approved, mission id: {} is full so they are number {} on the waitlist",
+                request_id, mission_id, position
+            ),
+        )
+            .into_response(),
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Join request id: {} for mission id: {} has been approved",
+                request_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn reject_join_request<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path((mission_id, request_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .reject_join_request(mission_id, request_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Join request id: {} for mission id: {} has been rejected",
+                request_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn create_invite<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    Json(new_mission_invite_model): Json<NewMissionInviteModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .create_invite(mission_id, brawler_id, new_mission_invite_model)
+        .await
+    {
+        Ok(created_invite) => (StatusCode::CREATED, Json(created_invite)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn invites<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .invites(mission_id, brawler_id)
+        .await
+    {
+        Ok(invites) => (StatusCode::OK, Json(invites)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn revoke_invite<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Path((mission_id, invite_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .revoke_invite(mission_id, invite_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Invite id: {} for mission id: {} has been revoked",
+                invite_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn redeem_invite<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(redeem_mission_invite_model): Json<RedeemMissionInviteModel>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .redeem_invite(brawler_id, redeem_mission_invite_model)
+        .await
+    {
+        Ok((mission_id, outcome)) => join_outcome_response(mission_id, brawler_id, outcome),
+        Err(e) => e.into_response(),
     }
 }
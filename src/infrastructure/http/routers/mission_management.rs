@@ -1,7 +1,6 @@
 use std::{ sync::Arc};
 
 use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, middleware, response::IntoResponse, routing::{delete, patch, post}};
-use tracing::error;
 
 use crate::{
     application::use_cases::mission_management::MissionManagementUseCase,
@@ -33,16 +32,7 @@ where
             });
             (StatusCode::CREATED, axum::Json(json_value)).into_response()
         }
-        Err(e) => {
-            error!("Failed to add mission: {:?}", e);
-            let error_message = e.to_string();
-            let status = if error_message.contains("Mission name is too long") {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, Json(serde_json::json!({"message": error_message}))).into_response()
-        }
+        Err(e) => e.into_response(),
     }
 }
 
@@ -65,7 +55,7 @@ where
             let response = format!("Edit mission success with id: {}", mission_id);
             (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -86,7 +76,7 @@ where
             let response = format!("Remove mission success with id: {}", mission_id);
             (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -104,6 +94,6 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         .route("/add", post(add))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
-        .route_layer(middleware::from_fn(authorization))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
         .with_state(Arc::new(mission_management_use_case))
 }
\ No newline at end of file
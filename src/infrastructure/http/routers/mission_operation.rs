@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use axum::{
-    Extension, Router,
+    Extension, Json, Router,
     extract::{Path, State},
     http::StatusCode,
     middleware,
@@ -10,17 +10,23 @@ use axum::{
 };
 
 use crate::{
-    application::use_cases::mission_operation::MissionOperationUseCase,
+    application::use_cases::{
+        crew_operation::CrewOperationUseCase, mission_operation::MissionOperationUseCase,
+    },
     domain::{
         repositories::{
-            mission_operation::MissionOperationRepository,
-            mission_viewing::MissionViewingRepository,
+            crew_operation::CrewOperationRepository, mission_operation::MissionOperationRepository,
+            mission_viewing::MissionViewingRepository, transaction_provider::TransactionProvider,
+        },
+        value_objects::{
+            crew_model::CrewRoleModel, mission_status_event_model::MissionStatusChangeModel,
+            mission_statuses::MissionStatuses,
         },
-        value_objects::mission_statuses::MissionStatuses,
     },
     infrastructure::{database::{
         postgresql_connection::PgPoolSquad,
         repositories::{
+            crew_operation::CrewOperationPostgres, diesel_transaction::DieselTransaction,
             mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres,
         },
     }, http::middleware::auth::authorization},
@@ -34,25 +40,41 @@ pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
         Arc::new(mission_operation_repository),
         Arc::new(mission_viewing_repository),
     );
+    // The handover takes the mission lock that joins and leaves use, so it goes through
+    // the crew operations.
+    let crew_operation_use_case = CrewOperationUseCase::new(
+        Arc::new(CrewOperationPostgres::new()),
+        Arc::new(DieselTransaction::new(Arc::clone(&db_pool))),
+    );
 
     Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
-        .route_layer(middleware::from_fn(authorization))
+        .route("/publish/{mission_id}", patch(publish))
+        .route("/cancel/{mission_id}", patch(cancel))
+        .route("/crew-role/{mission_id}/{brawler_id}", patch(set_crew_role))
+        .route(
+            "/transfer-chief/{mission_id}/{brawler_id}",
+            patch(transfer_chief).with_state(Arc::new(crew_operation_use_case)),
+        )
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
         .with_state(Arc::new(use_case))
 }
 
 pub async fn in_progress<T1, T2>(
     State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
-    Extension(chief_id): Extension<i32>,
+    Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    status_change: Option<Json<MissionStatusChangeModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
-    match mission_operation_use_case.in_progress(mission_id, chief_id).await {
+    match mission_operation_use_case
+        .in_progress(mission_id, brawler_id, &status_change.unwrap_or_default())
+        .await {
         Ok(mission_id) => {
             let response = format!(
                 "Mision ({}) is now {:?}",
@@ -61,21 +83,22 @@ where
             );
             (StatusCode::CREATED, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn to_completed<T1, T2>(
     State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
-    Extension(chief_id): Extension<i32>,
+    Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    status_change: Option<Json<MissionStatusChangeModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
     match mission_operation_use_case
-        .to_completed(mission_id, chief_id)
+        .to_completed(mission_id, brawler_id, &status_change.unwrap_or_default())
         .await
     {
         Ok(quest_id) => (
@@ -87,21 +110,22 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn to_failed<T1, T2>(
     State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
-    Extension(chief_id): Extension<i32>,
+    Extension(brawler_id): Extension<i32>,
     Path(mission_id): Path<i32>,
+    status_change: Option<Json<MissionStatusChangeModel>>,
 ) -> impl IntoResponse
 where
     T1: MissionOperationRepository + Send + Sync,
     T2: MissionViewingRepository + Send + Sync,
 {
     match mission_operation_use_case
-        .to_failed(mission_id, chief_id)
+        .to_failed(mission_id, brawler_id, &status_change.unwrap_or_default())
         .await
     {
         Ok(mission_id) => (
@@ -113,6 +137,112 @@ where
             ),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn publish<T1, T2>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    status_change: Option<Json<MissionStatusChangeModel>>,
+) -> impl IntoResponse
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .publish(mission_id, chief_id, &status_change.unwrap_or_default())
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!(
+                "Mission ({}) is now {:?}",
+                mission_id,
+                MissionStatuses::Open
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn cancel<T1, T2>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path(mission_id): Path<i32>,
+    status_change: Option<Json<MissionStatusChangeModel>>,
+) -> impl IntoResponse
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    match mission_operation_use_case
+        .cancel(mission_id, chief_id, &status_change.unwrap_or_default())
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!(
+                "Mission ({}) is now {:?}",
+                mission_id,
+                MissionStatuses::Cancelled
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn set_crew_role<T1, T2>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+    Json(crew_role_model): Json<CrewRoleModel>,
+) -> impl IntoResponse
+where
+    T1: MissionOperationRepository + Send + Sync,
+    T2: MissionViewingRepository + Send + Sync,
+{
+    let role = crew_role_model.role;
+    match mission_operation_use_case
+        .set_crew_role(mission_id, brawler_id, chief_id, role.clone())
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {} is now {} of mission id: {}",
+                brawler_id, role, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn transfer_chief<T1, T2>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
+    Extension(chief_id): Extension<i32>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T1: CrewOperationRepository<Connection = T2::Connection> + Send + Sync + 'static,
+    T2: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .transfer_chief(mission_id, brawler_id, chief_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!(
+                "Brawler id: {} is now the chief of mission id: {}",
+                brawler_id, mission_id
+            ),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
     }
 }
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::{Path, Query, State},
     http::StatusCode,
+    middleware,
     response::IntoResponse,
     routing::get,
 };
@@ -12,33 +13,55 @@ use crate::{
     application::use_cases::mission_viewing::MissionViewingUseCase,
     domain::{
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::mission_filter::MissionFilter,
+        value_objects::{
+            mission_filter::MissionFilter,
+            mission_viewer::{InviteTokenQuery, MissionViewer},
+            pagination::PageQuery,
+            tag_model::PopularTagsQuery,
+        },
     },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::mission_viewing::MissionViewingPostgres,
+        },
+        http::middleware::auth::optional_authorization,
     },
 };
 
 pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_viewing_repository = MissionViewingPostgres::new(db_pool);
-    let use_case = MissionViewingUseCase::new(Arc::new(mission_viewing_repository));
+    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
+    let use_case = Arc::new(MissionViewingUseCase::new(Arc::new(mission_viewing_repository)));
+
+    // Public, but a signed-in brawler may see the invite-only missions they belong to.
+    let viewer_aware_router = Router::new()
+        .route("/{mission_id}", get(view_details))
+        .route("/{mission_id}/crew", get(crew_roster))
+        .route("/{mission_id}/history", get(status_history))
+        .route_layer(middleware::from_fn_with_state(db_pool, optional_authorization))
+        .with_state(Arc::clone(&use_case));
 
     Router::new()
         .route("/gets", get(gets))
-        .route("/{mission_id}", get(view_details))
-        .with_state(Arc::new(use_case))
+        .route("/tags", get(popular_tags))
+        .with_state(use_case)
+        .merge(viewer_aware_router)
 }
 
 pub async fn view_details<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     Path(mission_id): Path<i32>,
+    brawler_id: Option<Extension<i32>>,
+    Query(invite_token_query): Query<InviteTokenQuery>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match mission_viewing_use_case.view_detail(mission_id).await {
+    let viewer = MissionViewer::new(brawler_id.map(|Extension(id)| id), invite_token_query);
+
+    match mission_viewing_use_case.view_detail(mission_id, &viewer).await {
         Ok(mission_model) => (StatusCode::OK, Json(mission_model)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
@@ -51,6 +74,54 @@ where
 {
     match mission_viewing_use_case.get(&filter).await {
         Ok(mission_models) => (StatusCode::OK, Json(mission_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
-}
\ No newline at end of file
+}
+
+pub async fn crew_roster<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+    page_query: Query<PageQuery>,
+    brawler_id: Option<Extension<i32>>,
+    Query(invite_token_query): Query<InviteTokenQuery>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    let viewer = MissionViewer::new(brawler_id.map(|Extension(id)| id), invite_token_query);
+
+    match mission_viewing_use_case.crew_roster(mission_id, &page_query, &viewer).await {
+        Ok(roster) => (StatusCode::OK, Json(roster)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn status_history<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+    brawler_id: Option<Extension<i32>>,
+    Query(invite_token_query): Query<InviteTokenQuery>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    let viewer = MissionViewer::new(brawler_id.map(|Extension(id)| id), invite_token_query);
+
+    match mission_viewing_use_case.status_history(mission_id, &viewer).await {
+        Ok(history) => (StatusCode::OK, Json(history)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn popular_tags<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    query: Query<PopularTagsQuery>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match mission_viewing_use_case.popular_tags(&query).await {
+        Ok(tags) => (StatusCode::OK, Json(tags)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -5,4 +5,7 @@ pub mod crew_operation;
 pub mod mission_management;
 pub mod mission_operation;
 pub mod mission_viewing;
+pub mod password;
+pub mod two_factor;
+pub mod well_known;
 pub mod default;
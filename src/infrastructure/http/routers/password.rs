@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    application::use_cases::password::PasswordUseCase,
+    config::config_loader::get_mailer_env,
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, mailer::Mailer, password_reset::PasswordResetRepository,
+            sessions::SessionRepository,
+        },
+        value_objects::password_model::{
+            ChangePasswordModel, ForgotPasswordModel, ResetPasswordModel,
+        },
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{
+                brawlers::BrawlerPostgres, password_reset::PasswordResetPostgres,
+                sessions::SessionPostgres,
+            },
+        },
+        http::middleware::auth::authorization,
+        mailer,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Result<Router> {
+    let mailer_env = get_mailer_env()?;
+    let mailer = mailer::from_env(&mailer_env)?;
+
+    let use_case = Arc::new(PasswordUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(PasswordResetPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(SessionPostgres::new(Arc::clone(&db_pool))),
+        mailer,
+        mailer_env.password_reset_url,
+    ));
+
+    let protected_router = Router::new()
+        .route("/change-password", post(change_password))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
+        .with_state(Arc::clone(&use_case));
+
+    Ok(Router::new()
+        .route("/forgot-password", post(forgot_password))
+        .route("/reset-password", post(reset_password))
+        .with_state(use_case)
+        .merge(protected_router))
+}
+
+pub async fn change_password<T1, T2, T3, T4>(
+    State(password_use_case): State<Arc<PasswordUseCase<T1, T2, T3, T4>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(change_password_model): Json<ChangePasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: Mailer + Send + Sync + ?Sized,
+{
+    match password_use_case
+        .change_password(brawler_id, change_password_model)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            "Password changed successfully, please login again",
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn forgot_password<T1, T2, T3, T4>(
+    State(password_use_case): State<Arc<PasswordUseCase<T1, T2, T3, T4>>>,
+    Json(forgot_password_model): Json<ForgotPasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: Mailer + Send + Sync + ?Sized,
+{
+    match password_use_case.forgot_password(forgot_password_model).await {
+        Ok(_) => (
+            StatusCode::OK,
+            "If the account exists, a password reset mail has been sent",
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn reset_password<T1, T2, T3, T4>(
+    State(password_use_case): State<Arc<PasswordUseCase<T1, T2, T3, T4>>>,
+    Json(reset_password_model): Json<ResetPasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: PasswordResetRepository + Send + Sync,
+    T3: SessionRepository + Send + Sync,
+    T4: Mailer + Send + Sync + ?Sized,
+{
+    match password_use_case.reset_password(reset_password_model).await {
+        Ok(_) => (StatusCode::OK, "Password reset successfully").into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    application::use_cases::two_factor::TwoFactorUseCase,
+    domain::{
+        repositories::{brawlers::BrawlerRepository, recovery_codes::RecoveryCodeRepository},
+        value_objects::two_factor_model::TotpCodeModel,
+    },
+    infrastructure::{
+        database::{
+            postgresql_connection::PgPoolSquad,
+            repositories::{brawlers::BrawlerPostgres, recovery_codes::RecoveryCodePostgres},
+        },
+        http::middleware::auth::authorization,
+    },
+};
+
+pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
+    let use_case = Arc::new(TwoFactorUseCase::new(
+        Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+        Arc::new(RecoveryCodePostgres::new(Arc::clone(&db_pool))),
+    ));
+
+    Router::new()
+        .route("/2fa/enroll", post(enroll))
+        .route("/2fa/verify", post(verify))
+        .route("/2fa/disable", post(disable))
+        .route_layer(middleware::from_fn_with_state(Arc::clone(&db_pool), authorization))
+        .with_state(use_case)
+}
+
+pub async fn enroll<T1, T2>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RecoveryCodeRepository + Send + Sync,
+{
+    match two_factor_use_case.enroll(brawler_id).await {
+        Ok(enrollment) => (StatusCode::OK, Json(enrollment)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn verify<T1, T2>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(totp_code_model): Json<TotpCodeModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RecoveryCodeRepository + Send + Sync,
+{
+    match two_factor_use_case.verify(brawler_id, totp_code_model).await {
+        Ok(recovery_codes) => (StatusCode::OK, Json(recovery_codes)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn disable<T1, T2>(
+    State(two_factor_use_case): State<Arc<TwoFactorUseCase<T1, T2>>>,
+    Extension(brawler_id): Extension<i32>,
+    Json(totp_code_model): Json<TotpCodeModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RecoveryCodeRepository + Send + Sync,
+{
+    match two_factor_use_case.disable(brawler_id, totp_code_model).await {
+        Ok(_) => (StatusCode::OK, "Two-factor authentication disabled").into_response(),
+        Err(e) => e.into_response(),
+    }
+}
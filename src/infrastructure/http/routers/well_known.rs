@@ -0,0 +1,15 @@
+use axum::{Json, Router, http::StatusCode, response::IntoResponse, routing::get};
+
+use crate::{domain::app_error::AppError, infrastructure::jwt::access_keys};
+
+/// Public keys other services can verify meetup access tokens with.
+pub async fn jwks() -> impl IntoResponse {
+    match access_keys() {
+        Ok(keys) => (StatusCode::OK, Json(keys.jwks().clone())).into_response(),
+        Err(e) => AppError::Internal(e).into_response(),
+    }
+}
+
+pub fn routes() -> Router {
+    Router::new().route("/.well-known/jwks.json", get(jwks))
+}
@@ -0,0 +1,38 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{
+    config::config_model::CloudinaryEnv,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+    },
+    infrastructure::cloudinary::{self, UploadImageOptions},
+};
+
+pub struct CloudinaryStorage {
+    cloud_env: CloudinaryEnv,
+}
+
+impl CloudinaryStorage {
+    pub fn new(cloud_env: CloudinaryEnv) -> Self {
+        Self { cloud_env }
+    }
+}
+
+#[async_trait]
+impl ImageStorage for CloudinaryStorage {
+    async fn upload(&self, base64_image: Base64Image, folder: &str) -> Result<UploadedImage> {
+        let option = UploadImageOptions {
+            folder: Some(folder.to_string()),
+            public_id: None,
+            transformation: None,
+        };
+
+        cloudinary::upload(&self.cloud_env, base64_image, option).await
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        cloudinary::destroy(&self.cloud_env, public_id).await
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::image_storage::ImageStorage,
+    value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+};
+
+/// Writes each image under `IMAGE_LOCAL_DIR`, in a subdirectory per folder, and hands
+/// back its URL below `IMAGE_LOCAL_BASE_URL`, where the static file handler serves it.
+pub struct LocalImageStorage {
+    root_dir: PathBuf,
+    base_url: String,
+}
+
+impl LocalImageStorage {
+    pub fn new(root_dir: &str, base_url: &str) -> Self {
+        Self {
+            root_dir: PathBuf::from(root_dir),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    // The public id is the path relative to `root_dir`; refuse anything that could escape it.
+    fn path_of(&self, public_id: &str) -> Result<PathBuf> {
+        let relative = Path::new(public_id);
+        if !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(anyhow::anyhow!("Invalid image id: {}", public_id));
+        }
+
+        Ok(self.root_dir.join(relative))
+    }
+}
+
+#[async_trait]
+impl ImageStorage for LocalImageStorage {
+    async fn upload(&self, base64_image: Base64Image, folder: &str) -> Result<UploadedImage> {
+        let public_id = format!(
+            "{}/{}.{}",
+            folder,
+            Uuid::new_v4().simple(),
+            base64_image.extension()
+        );
+        let path = self.path_of(&public_id)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, base64_image.to_bytes()?).await?;
+
+        let url = format!("{}/{}", self.base_url, public_id);
+        Ok(UploadedImage::new(url, public_id))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.path_of(public_id)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
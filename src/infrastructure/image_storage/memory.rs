@@ -0,0 +1,54 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::image_storage::ImageStorage,
+    value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+};
+
+/// Keeps images in memory so tests can check what was uploaded and deleted.
+#[derive(Default)]
+pub struct InMemoryImageStorage {
+    images: Mutex<HashMap<String, String>>,
+}
+
+impl InMemoryImageStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contains(&self, public_id: &str) -> bool {
+        self.images.lock().unwrap().contains_key(public_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl ImageStorage for InMemoryImageStorage {
+    async fn upload(&self, base64_image: Base64Image, folder: &str) -> Result<UploadedImage> {
+        let public_id = format!("{}/{}", folder, Uuid::new_v4().simple());
+        let url = format!("memory://{}", public_id);
+
+        self.images
+            .lock()
+            .unwrap()
+            .insert(public_id.clone(), base64_image.into_inner());
+
+        Ok(UploadedImage::new(url, public_id))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        self.images.lock().unwrap().remove(public_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+pub mod cloudinary;
+pub mod local;
+pub mod memory;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{config::config_model::ImageStorageEnv, domain::repositories::image_storage::ImageStorage};
+
+use self::{cloudinary::CloudinaryStorage, local::LocalImageStorage};
+
+pub fn from_env(image_storage_env: &ImageStorageEnv) -> Result<Arc<dyn ImageStorage + Send + Sync>> {
+    match &image_storage_env.cloudinary {
+        Some(cloudinary_env) => Ok(Arc::new(CloudinaryStorage::new(cloudinary_env.clone()))),
+        None => Ok(Arc::new(LocalImageStorage::new(
+            &image_storage_env.local_dir,
+            &image_storage_env.local_base_url,
+        ))),
+    }
+}
@@ -4,4 +4,10 @@ use serde::{Deserialize, Serialize};
 pub struct LoginModel {
     pub username: String,
     pub password: String,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaLoginModel {
+    pub mfa_token: String,
+    pub code: String,
+}
@@ -10,9 +10,34 @@ pub struct Passport {
     pub avatar_url: Option<String>,
 }
 
+/// Returned by login instead of a passport when the brawler has 2FA enabled.
+/// The `mfa_token` has to be sent back together with a TOTP or recovery code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MfaChallenge {
+    pub mfa_token: String,
+    /// Seconds until `mfa_token` expires.
+    pub expires_in: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum LoginOutcome {
+    Passport(Passport),
+    MfaPending(MfaChallenge),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    Access,
+    Refresh,
+    MfaPending,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
+    pub jti: String,
+    pub typ: TokenType,
     pub exp: usize,
     pub iat: usize,
-}
\ No newline at end of file
+}
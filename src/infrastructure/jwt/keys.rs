@@ -0,0 +1,214 @@
+use anyhow::Result;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+};
+use serde::{Serialize, de::DeserializeOwned};
+use simple_asn1::ASN1Block;
+
+use crate::config::config_model::JwtEnv;
+
+struct VerificationKey {
+    kid: Option<String>,
+    decoding_key: DecodingKey,
+}
+
+/// One signing key plus every key that tokens may still be verified with.
+///
+/// With `HS256` there is a single shared secret and no `kid`. With `RS256`/`EdDSA`
+/// the private key signs new tokens under `signing_key_id`, while retired public keys
+/// stay in `JWT_PUBLIC_KEYS` until the tokens they signed have expired.
+pub struct JwtKeys {
+    algorithm: Algorithm,
+    signing_key_id: Option<String>,
+    encoding_key: EncodingKey,
+    verification_keys: Vec<VerificationKey>,
+    jwks: JwkSet,
+}
+
+impl JwtKeys {
+    pub fn from_secret(secret: &str) -> Self {
+        Self {
+            algorithm: Algorithm::HS256,
+            signing_key_id: None,
+            encoding_key: EncodingKey::from_secret(secret.as_ref()),
+            verification_keys: vec![VerificationKey {
+                kid: None,
+                decoding_key: DecodingKey::from_secret(secret.as_ref()),
+            }],
+            // A shared secret must never be published.
+            jwks: JwkSet { keys: vec![] },
+        }
+    }
+
+    pub fn from_env(jwt_env: &JwtEnv) -> Result<Self> {
+        let algorithm = match jwt_env.algorithm.as_str() {
+            "HS256" => {
+                let secret = jwt_env
+                    .secret
+                    .as_deref()
+                    .ok_or_else(|| anyhow::anyhow!("JWT_USER_SECRET not set"))?;
+                return Ok(Self::from_secret(secret));
+            }
+            "RS256" => Algorithm::RS256,
+            "EdDSA" => Algorithm::EdDSA,
+            other => return Err(anyhow::anyhow!("Unsupported JWT_ALGORITHM: {}", other)),
+        };
+
+        let signing_key_id = jwt_env
+            .signing_key_id
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("JWT_SIGNING_KEY_ID not set"))?;
+        let private_key_path = jwt_env
+            .private_key_path
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("JWT_PRIVATE_KEY_PATH not set"))?;
+
+        let private_pem = std::fs::read(private_key_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", private_key_path, e))?;
+        let encoding_key = match algorithm {
+            Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem)?,
+            _ => EncodingKey::from_ed_pem(&private_pem)?,
+        };
+
+        let mut verification_keys = Vec::new();
+        let mut jwks = JwkSet { keys: vec![] };
+        for public_key in &jwt_env.public_keys {
+            let public_pem = std::fs::read(&public_key.public_key_path).map_err(|e| {
+                anyhow::anyhow!("Failed to read {}: {}", public_key.public_key_path, e)
+            })?;
+            let jwk = public_jwk(algorithm, &public_key.kid, &public_pem)?;
+
+            verification_keys.push(VerificationKey {
+                kid: Some(public_key.kid.clone()),
+                decoding_key: DecodingKey::from_jwk(&jwk)?,
+            });
+            jwks.keys.push(jwk);
+        }
+
+        let keys = Self {
+            algorithm,
+            signing_key_id: Some(signing_key_id),
+            encoding_key,
+            verification_keys,
+            jwks,
+        };
+
+        // Fail at startup rather than on the first login if the pair does not match.
+        let probe = keys.encode(&serde_json::json!({ "exp": usize::MAX }))?;
+        keys.decode::<serde_json::Value>(&probe).map_err(|_| {
+            anyhow::anyhow!("JWT_PUBLIC_KEYS has no public key matching JWT_SIGNING_KEY_ID")
+        })?;
+
+        Ok(keys)
+    }
+
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+
+    pub fn encode<T: Serialize>(&self, claims: &T) -> Result<String> {
+        let mut header = Header::new(self.algorithm);
+        header.kid = self.signing_key_id.clone();
+
+        Ok(encode(&header, claims, &self.encoding_key)?)
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, token: &str) -> Result<T> {
+        let header = decode_header(token)?;
+        if header.alg != self.algorithm {
+            return Err(anyhow::anyhow!("Unexpected token algorithm"));
+        }
+
+        let verification_key = self
+            .verification_keys
+            .iter()
+            .find(|key| key.kid == header.kid)
+            .ok_or_else(|| anyhow::anyhow!("Unknown token key id"))?;
+
+        let token_data = decode::<T>(
+            token,
+            &verification_key.decoding_key,
+            &Validation::new(self.algorithm),
+        )?;
+        Ok(token_data.claims)
+    }
+}
+
+fn public_jwk(algorithm: Algorithm, kid: &str, pem_bytes: &[u8]) -> Result<Jwk> {
+    let pem = pem::parse(pem_bytes)?;
+
+    let (key_algorithm, parameters) = match (algorithm, pem.tag()) {
+        (Algorithm::RS256, "RSA PUBLIC KEY") => {
+            (KeyAlgorithm::RS256, rsa_parameters(pem.contents())?)
+        }
+        (Algorithm::RS256, "PUBLIC KEY") => (
+            KeyAlgorithm::RS256,
+            rsa_parameters(&subject_public_key(pem.contents())?)?,
+        ),
+        (Algorithm::EdDSA, "PUBLIC KEY") => {
+            let x = subject_public_key(pem.contents())?;
+            if x.len() != 32 {
+                return Err(anyhow::anyhow!("Public key {} is not an Ed25519 key", kid));
+            }
+            (
+                KeyAlgorithm::EdDSA,
+                AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(x),
+                }),
+            )
+        }
+        (_, tag) => {
+            return Err(anyhow::anyhow!(
+                "Public key {} has unexpected PEM type {} for {:?}",
+                kid,
+                tag,
+                algorithm
+            ));
+        }
+    };
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(kid.to_string()),
+            ..Default::default()
+        },
+        algorithm: parameters,
+    })
+}
+
+// SubjectPublicKeyInfo ::= SEQUENCE { algorithm AlgorithmIdentifier, subjectPublicKey BIT STRING }
+fn subject_public_key(der: &[u8]) -> Result<Vec<u8>> {
+    match simple_asn1::from_der(der)?.as_slice() {
+        [ASN1Block::Sequence(_, fields)] => match fields.as_slice() {
+            [ASN1Block::Sequence(..), ASN1Block::BitString(_, _, key)] => Ok(key.clone()),
+            _ => Err(anyhow::anyhow!("Malformed SubjectPublicKeyInfo")),
+        },
+        _ => Err(anyhow::anyhow!("Malformed SubjectPublicKeyInfo")),
+    }
+}
+
+// RSAPublicKey ::= SEQUENCE { modulus INTEGER, publicExponent INTEGER }
+fn rsa_parameters(der: &[u8]) -> Result<AlgorithmParameters> {
+    match simple_asn1::from_der(der)?.as_slice() {
+        [ASN1Block::Sequence(_, fields)] => match fields.as_slice() {
+            [ASN1Block::Integer(_, n), ASN1Block::Integer(_, e)] => {
+                Ok(AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: URL_SAFE_NO_PAD.encode(n.to_bytes_be().1),
+                    e: URL_SAFE_NO_PAD.encode(e.to_bytes_be().1),
+                }))
+            }
+            _ => Err(anyhow::anyhow!("Malformed RSA public key")),
+        },
+        _ => Err(anyhow::anyhow!("Malformed RSA public key")),
+    }
+}
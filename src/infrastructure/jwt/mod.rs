@@ -1,24 +1,29 @@
 pub mod jwt_model;
 pub mod authentication_model;
+pub mod keys;
+
+use std::sync::OnceLock;
 
 use anyhow::Result;
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
-use self::jwt_model::Claims;
+use self::{jwt_model::Claims, keys::JwtKeys};
+use crate::config::config_loader::get_jwt_env;
+
+static ACCESS_KEYS: OnceLock<JwtKeys> = OnceLock::new();
+
+/// Keys for access tokens, loaded once from the env on first use.
+pub fn access_keys() -> Result<&'static JwtKeys> {
+    if let Some(keys) = ACCESS_KEYS.get() {
+        return Ok(keys);
+    }
 
-pub fn generate_token(secret: String, claims: &Claims) -> Result<String> {
-    let token = encode(
-        &Header::default(),
-        claims,
-        &EncodingKey::from_secret(secret.as_ref()),
-    )?;
-    Ok(token)
+    let keys = JwtKeys::from_env(&get_jwt_env()?)?;
+    Ok(ACCESS_KEYS.get_or_init(|| keys))
 }
 
-pub fn verify_token(secret: String, token: String) -> Result<Claims> {
-    let token_data = decode::<Claims>(
-        &token,
-        &DecodingKey::from_secret(secret.as_ref()),
-        &Validation::default(),
-    )?;
-    Ok(token_data.claims)
-}
\ No newline at end of file
+pub fn generate_token(keys: &JwtKeys, claims: &Claims) -> Result<String> {
+    keys.encode(claims)
+}
+
+pub fn verify_token(keys: &JwtKeys, token: String) -> Result<Claims> {
+    keys.decode::<Claims>(&token)
+}
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::domain::{repositories::mailer::Mailer, value_objects::mail_message::MailMessage};
+
+/// Writes every mail into an outbox directory instead of sending it.
+/// Meant for local development, where there is no SMTP server around.
+pub struct FileMailer {
+    outbox_dir: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(outbox_dir: &str) -> Self {
+        Self {
+            outbox_dir: PathBuf::from(outbox_dir),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, message: MailMessage) -> Result<()> {
+        tokio::fs::create_dir_all(&self.outbox_dir).await?;
+
+        let file_name = format!(
+            "{}-{}.txt",
+            Utc::now().format("%Y%m%d%H%M%S"),
+            Uuid::new_v4().simple()
+        );
+        let path = self.outbox_dir.join(file_name);
+        let content = format!(
+            "To: {}\nSubject: {}\n\n{}\n",
+            message.to, message.subject, message.body
+        );
+
+        tokio::fs::write(&path, content).await?;
+        info!("Mail to {} written to {}", message.to, path.display());
+
+        Ok(())
+    }
+}
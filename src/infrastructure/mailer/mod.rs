@@ -0,0 +1,17 @@
+pub mod file;
+pub mod smtp;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{config::config_model::MailerEnv, domain::repositories::mailer::Mailer};
+
+use self::{file::FileMailer, smtp::SmtpMailer};
+
+pub fn from_env(mailer_env: &MailerEnv) -> Result<Arc<dyn Mailer + Send + Sync>> {
+    match &mailer_env.smtp {
+        Some(smtp_env) => Ok(Arc::new(SmtpMailer::new(smtp_env, &mailer_env.from)?)),
+        None => Ok(Arc::new(FileMailer::new(&mailer_env.outbox_dir))),
+    }
+}
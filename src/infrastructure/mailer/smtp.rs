@@ -0,0 +1,50 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{Mailbox, header::ContentType},
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::{
+    config::config_model::SmtpEnv,
+    domain::{repositories::mailer::Mailer, value_objects::mail_message::MailMessage},
+};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(smtp_env: &SmtpEnv, from: &str) -> Result<Self> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp_env.host)?
+            .port(smtp_env.port)
+            .credentials(Credentials::new(
+                smtp_env.username.clone(),
+                smtp_env.password.clone(),
+            ))
+            .build();
+
+        Ok(Self {
+            transport,
+            from: from.parse()?,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: MailMessage) -> Result<()> {
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(message.to.parse()?)
+            .subject(message.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body)?;
+
+        self.transport.send(email).await?;
+
+        Ok(())
+    }
+}
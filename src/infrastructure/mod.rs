@@ -1,4 +1,9 @@
 pub mod database;
 pub mod http;
 pub mod argon2;
-pub mod jwt;
\ No newline at end of file
+pub mod cloudinary;
+pub mod image_storage;
+pub mod jwt;
+pub mod mailer;
+pub mod sha256;
+pub mod totp;
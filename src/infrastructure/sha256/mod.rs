@@ -0,0 +1,10 @@
+use sha2::{Digest, Sha256};
+
+/// Hex SHA-256 digest of `value`. Tokens that are looked up by value, like password
+/// reset tokens and mission invites, are stored this way rather than as a salted
+/// argon2 hash, which could not be searched for.
+pub fn sha256_hex(value: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(value);
+    format!("{:x}", hasher.finalize())
+}
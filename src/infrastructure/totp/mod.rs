@@ -0,0 +1,53 @@
+use anyhow::Result;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const ISSUER: &str = "Meetup";
+
+const STEP_SECONDS: u64 = 30;
+
+fn build(secret: &str, account_name: &str) -> Result<TOTP> {
+    build_with_skew(secret, account_name, 1)
+}
+
+fn build_with_skew(secret: &str, account_name: &str, skew: u8) -> Result<TOTP> {
+    let secret_bytes = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+    TOTP::new(
+        Algorithm::SHA1,
+        6,
+        skew,
+        STEP_SECONDS,
+        secret_bytes,
+        Some(ISSUER.to_string()),
+        account_name.replace(':', "_"),
+    )
+    .map_err(|e| anyhow::anyhow!(e.to_string()))
+}
+
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+pub fn otpauth_uri(secret: &str, account_name: &str) -> Result<String> {
+    Ok(build(secret, account_name)?.get_url())
+}
+
+/// Returns the time step `code` belongs to, allowing one step of clock drift either way,
+/// or `None` when it does not match. Callers persist the step to refuse replays.
+pub fn matching_step(secret: &str, code: &str) -> Result<Option<i64>> {
+    let totp = build_with_skew(secret, "", 0)?;
+    let current = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs()
+        / STEP_SECONDS;
+
+    Ok((current.saturating_sub(1)..=current + 1)
+        .find(|step| totp.check(code, step * STEP_SECONDS))
+        .map(|step| step as i64))
+}
+
+pub fn generate_current(secret: &str) -> Result<String> {
+    Ok(build(secret, "")?.generate_current()?)
+}
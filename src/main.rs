@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use server::{
     config::config_loader,
-    infrastructure::{database::postgresql_connection, http::http_serv::start},
+    infrastructure::{database::postgresql_connection, http::http_serv::start, jwt},
 };
 use tracing::{error, info};
 
@@ -22,6 +22,11 @@ async fn main() {
 
     info!(".ENV LOADED");
 
+    if let Err(e) = jwt::access_keys() {
+        error!("Failed to load JWT keys: {}", e);
+        std::process::exit(1);
+    }
+
     let postgres_pool = match postgresql_connection::establish_connection(&dotenvy_env.database.url)
     {
         Ok(pool) => pool,
@@ -32,8 +37,9 @@ async fn main() {
     };
     info!("Connected DB");
 
-    start(Arc::new(dotenvy_env), Arc::new(postgres_pool))
-        .await
-        .expect("Failed to start server");
+    if let Err(e) = start(Arc::new(dotenvy_env), Arc::new(postgres_pool)).await {
+        error!("Failed to start server: {}", e);
+        std::process::exit(1);
+    }
 
     }
\ No newline at end of file